@@ -0,0 +1,33 @@
+//! Parsing and both parts of every day, on its real input or else on its example
+//!
+//! Run a single day with `cargo bench -p aoc -- d05/`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn days(c: &mut Criterion) {
+    aoc_core::set_quiet(true);
+    for day in 1..=aoc::days::LAST {
+        let (Ok(input), Some(mut solver)) = (aoc::days::bench_input(day), aoc::days::solver(day))
+        else {
+            continue;
+        };
+        if solver.configure(&input.args).is_err() {
+            continue;
+        }
+
+        // Timings on the example get their own group, apart from the real ones
+        let name = match input.name {
+            aoc_core::input::REAL => format!("d{day:02}"),
+            name => format!("d{day:02}-{name}"),
+        };
+        let text = &input.text;
+        let mut group = c.benchmark_group(name);
+        group.bench_function("parse", |b| b.iter(|| solver.parse(black_box(text))));
+        group.bench_function("part_1", |b| b.iter(|| solver.part_1(black_box(text))));
+        group.bench_function("part_2", |b| b.iter(|| solver.part_2(black_box(text))));
+        group.finish();
+    }
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,101 @@
+//! Quick timings of the days, summed up for the whole calendar
+//!
+//! Every step is repeated for the given time and the median run is kept. Both parts parse the
+//! input themselves, so the total of a day is part 1 plus part 2. Days without a real input are
+//! timed on their example instead, and left out of the total. The criterion suite in
+//! `aoc/benches` measures the same steps more carefully, `cargo bench -p aoc`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use aoc::days;
+use aoc_core::input::REAL;
+use eyre::Result;
+
+/// Median time of `f`, run at least once and then as long as `budget` allows
+fn measure<T>(budget: Duration, mut f: impl FnMut() -> Result<T>) -> Result<Duration> {
+    let mut runs = vec![];
+    let start = Instant::now();
+    while runs.is_empty() || start.elapsed() < budget {
+        let t = Instant::now();
+        black_box(f()?);
+        runs.push(t.elapsed());
+    }
+    runs.sort();
+    Ok(runs[runs.len() / 2])
+}
+
+fn format_duration(d: Option<Duration>) -> String {
+    let Some(d) = d else {
+        return "-".to_string();
+    };
+    let ns = d.as_nanos() as f64;
+    if ns < 1e3 {
+        format!("{ns:.0} ns")
+    } else if ns < 1e6 {
+        format!("{:.1} µs", ns / 1e3)
+    } else if ns < 1e9 {
+        format!("{:.1} ms", ns / 1e6)
+    } else {
+        format!("{:.2} s", ns / 1e9)
+    }
+}
+
+fn print_row(name: &str, steps: [Option<Duration>; 3]) {
+    let total = steps[1].zip(steps[2]).map(|(a, b)| a + b);
+    let [parse, part_1, part_2, total] = [steps[0], steps[1], steps[2], total].map(format_duration);
+    println!("{name:<6}{parse:>11}{part_1:>11}{part_2:>11}{total:>11}");
+}
+
+pub fn bench(days: Vec<u8>, budget: Duration) -> Result<()> {
+    // Diagnostics would be printed on every run of the timed loops
+    aoc_core::set_quiet(true);
+    let mut rows = vec![];
+
+    for day in days {
+        let Some(mut solver) = days::solver(day) else {
+            eprintln!("Day {day:02}: no solver");
+            continue;
+        };
+        let input = match days::bench_input(day).and_then(|input| {
+            solver.configure(&input.args)?;
+            Ok(input)
+        }) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Day {day:02}: {e:#}");
+                continue;
+            }
+        };
+
+        let text = &input.text;
+        let steps = [
+            measure(budget, || solver.parse(text)),
+            measure(budget, || solver.part_1(text)),
+            measure(budget, || solver.part_2(text)),
+        ];
+        let steps = steps.map(|step| {
+            step.map_err(|e| eprintln!("Day {day:02}: error: {e:#}")).ok()
+        });
+        rows.push((day, input.name == REAL, steps));
+    }
+
+    println!("{:<6}{:>11}{:>11}{:>11}{:>11}", "Day", "Parse", "Part 1", "Part 2", "Total");
+    let mut totals = [Some(Duration::ZERO); 3];
+    for &(day, real, steps) in &rows {
+        if !real {
+            print_row(&format!("{day:02}*"), steps);
+            continue;
+        }
+        print_row(&format!("{day:02}"), steps);
+        for (total, step) in totals.iter_mut().zip(steps) {
+            *total = total.zip(step).map(|(a, b)| a + b);
+        }
+    }
+    print_row("Total", totals);
+    if rows.iter().any(|&(_, real, _)| !real) {
+        println!("\n* no real input, timed on the example and left out of the total");
+    }
+
+    Ok(())
+}
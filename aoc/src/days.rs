@@ -0,0 +1,76 @@
+use std::fs;
+use std::path::PathBuf;
+
+use aoc_core::input::{self, REAL};
+use aoc_core::Solver;
+use eyre::Result;
+
+/// Last day with a registered solver
+pub const LAST: u8 = 18;
+
+pub fn solver(day: u8) -> Option<Box<dyn Solver>> {
+    Some(match day {
+        1 => Box::new(d01::Solution::default()),
+        2 => Box::new(d02::Solution::default()),
+        3 => Box::new(d03::Solution::default()),
+        4 => Box::new(d04::Solution::default()),
+        5 => Box::new(d05::Solution::default()),
+        6 => Box::new(d06::Solution::default()),
+        7 => Box::new(d07::Solution::default()),
+        8 => Box::new(d08::Solution),
+        9 => Box::new(d09::Solution),
+        10 => Box::new(d10::Solution),
+        11 => Box::new(d11::Solution::default()),
+        12 => Box::new(d12::Solution),
+        13 => Box::new(d13::Solution),
+        14 => Box::new(d14::Solution::default()),
+        15 => Box::new(d15::Solution::default()),
+        16 => Box::new(d16::Solution::default()),
+        17 => Box::new(d17::Solution::default()),
+        18 => Box::new(d18::Solution),
+        _ => return None,
+    })
+}
+
+/// Crate directory of a day in the workspace
+pub fn day_dir(day: u8) -> PathBuf {
+    aoc_core::input::workspace_dir().join(format!("d{day:02}"))
+}
+
+/// Input a day is timed on, the real one or else the example
+pub struct BenchInput {
+    /// Name of the input, see [`aoc_core::input`]
+    pub name: &'static str,
+    pub text: String,
+    /// Puzzle parameters recorded for the input in `answers.toml`
+    pub args: Vec<String>,
+}
+
+pub fn bench_input(day: u8) -> Result<BenchInput> {
+    let (name, text) = match input::read(day, REAL) {
+        Ok(text) => (REAL, text),
+        Err(_) => ("example", input::read(day, "example")?),
+    };
+    Ok(BenchInput {
+        name,
+        text,
+        args: recorded_args(day, name)?,
+    })
+}
+
+/// `args` of an input in the `answers.toml` of a day, empty when there are none
+fn recorded_args(day: u8, name: &str) -> Result<Vec<String>> {
+    let Ok(answers) = fs::read_to_string(day_dir(day).join("answers.toml")) else {
+        return Ok(vec![]);
+    };
+    let answers: toml::Value = toml::from_str(&answers)?;
+    let args = answers
+        .get(name)
+        .and_then(|section| section.get("args"))
+        .and_then(|args| args.as_array());
+    Ok(args
+        .into_iter()
+        .flatten()
+        .filter_map(|arg| arg.as_str().map(String::from))
+        .collect())
+}
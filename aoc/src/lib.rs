@@ -0,0 +1,3 @@
+//! Registry of the days, shared by the `aoc` runner and the benchmarks
+
+pub mod days;
@@ -0,0 +1,181 @@
+use std::io::IsTerminal;
+use std::str::FromStr;
+use std::time::Duration;
+
+use aoc::days;
+use aoc_core::output::{self, Format};
+use aoc_core::Answer;
+use clap::{Parser, Subcommand};
+use eyre::{bail, eyre, Result, WrapErr};
+
+mod bench;
+mod new;
+mod verify;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve the selected days
+    Run {
+        /// A day (`7`), a range (`1..=18`, `1..18`) or `all`
+        days: Days,
+        /// Only solve one of the two parts
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Named input to solve, such as `example`, instead of stdin or the real input
+        #[arg(long)]
+        input: Option<String>,
+        /// Print the answers for people or as JSON, one line per day
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Silence the diagnostics of the solvers
+        #[arg(short, long)]
+        quiet: bool,
+        /// Puzzle parameters of the day, such as `-- --row 10 --bound 20`
+        #[arg(last = true)]
+        args: Vec<String>,
+    },
+    /// Check the solvers against the answers recorded in `dNN/answers.toml`
+    Verify {
+        /// A day (`7`), a range (`1..=18`, `1..18`) or `all`
+        #[arg(default_value = "all")]
+        days: Days,
+        /// Only check one of the two parts
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+    },
+    /// Create `dNN/` from `template.rs` and register it in the workspace
+    New {
+        /// Day to create, the one after the last registered day by default
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+    },
+    /// Time parsing and both parts of the selected days, with the total for the calendar
+    Bench {
+        /// A day (`7`), a range (`1..=18`, `1..18`) or `all`
+        #[arg(default_value = "all")]
+        days: Days,
+        /// Time spent measuring each step, in milliseconds
+        #[arg(long, default_value_t = 200)]
+        time: u64,
+    },
+}
+
+/// Selection of days from the command line
+#[derive(Clone)]
+struct Days(Vec<u8>);
+
+impl FromStr for Days {
+    type Err = eyre::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // The end of an exclusive range may be one past the last day
+        let day = |d: &str, last: u8| -> Result<u8> {
+            let d = d.trim().parse().map_err(|_| eyre!("invalid day `{d}`"))?;
+            if !(1..=last).contains(&d) {
+                bail!("day {d} is not in 1..={last}");
+            }
+            Ok(d)
+        };
+
+        let range = if s == "all" {
+            1..=days::LAST
+        } else if let Some((a, b)) = s.split_once("..=") {
+            day(a, days::LAST)?..=day(b, days::LAST)?
+        } else if let Some((a, b)) = s.split_once("..") {
+            day(a, days::LAST)?..=day(b, days::LAST + 1)? - 1
+        } else {
+            let d = day(s, days::LAST)?;
+            d..=d
+        };
+
+        if range.is_empty() {
+            bail!("empty range `{s}`");
+        }
+        Ok(Self(range.collect()))
+    }
+}
+
+fn print_answer(part: u8, answer: &Result<Answer>) {
+    let s = match answer {
+        Ok(answer) => answer.to_string(),
+        Err(e) => format!("error: {e:#}"),
+    };
+    if s.contains('\n') {
+        println!("  Part {part}:");
+        s.lines().for_each(|l| println!("    {l}"));
+    } else {
+        println!("  Part {part}: {s}");
+    }
+}
+
+fn run(Days(days): Days, part: Option<u8>, input: Option<String>, format: Format, args: &[String]) -> Result<()> {
+    // A single day can be fed from a pipe, otherwise use the inputs on disk
+    let piped = input.is_none() && days.len() == 1 && !std::io::stdin().is_terminal();
+    let name = input.as_deref().unwrap_or(aoc_core::input::REAL);
+
+    for day in days {
+        let mut solver = days::solver(day).ok_or_else(|| eyre!("no solver for day {day}"))?;
+        solver.configure(args).wrap_err_with(|| format!("Day {day:02}"))?;
+
+        let input = if piped {
+            aoc_core::read_input()?
+        } else {
+            match aoc_core::input::read(day, name) {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("{e:#}");
+                    continue;
+                }
+            }
+        };
+
+        let parts: Vec<u8> = [1, 2].into_iter().filter(|&p| part.is_none() || part == Some(p)).collect();
+        let solved = output::solve(solver.as_ref(), &input, &parts);
+        match format {
+            Format::Text => {
+                println!("Day {day:02}");
+                solved.iter().for_each(|s| print_answer(s.part, &s.answer));
+            }
+            Format::Json => println!("{}", output::json(day, &solved)),
+        }
+    }
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run { days, part, input, format, quiet, args } => {
+            aoc_core::set_quiet(quiet);
+            run(days, part, input, format, &args)
+        }
+        Command::Verify { days: Days(days), part } => verify::verify(days, part),
+        Command::New { day } => new::new(day.unwrap_or(days::LAST + 1)),
+        Command::Bench { days: Days(days), time } => bench::bench(days, Duration::from_millis(time)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_ranges() {
+        let last = days::LAST;
+        let all = "all".parse::<Days>().unwrap().0;
+        assert_eq!(format!("1..{}", last + 1).parse::<Days>().unwrap().0, all);
+        assert_eq!("3..5".parse::<Days>().unwrap().0, [3, 4]);
+        assert_eq!("3..=5".parse::<Days>().unwrap().0, [3, 4, 5]);
+        assert!(format!("1..{}", last + 2).parse::<Days>().is_err());
+        assert!(format!("1..={}", last + 1).parse::<Days>().is_err());
+        assert!("4..4".parse::<Days>().is_err());
+    }
+}
@@ -0,0 +1,138 @@
+//! Scaffolding of a new day: `dNN/` created from `template.rs` and registered in the workspace,
+//! the `aoc` dependencies and the [`days`] dispatch
+
+use std::fs;
+use std::path::Path;
+
+use aoc::days;
+use eyre::{bail, Result, WrapErr};
+
+const MANIFEST: &str = r#"[package]
+name = "dNN"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc_core = { path = "../aoc_core" }
+aoc_grid = { path = "../aoc_grid" }
+eyre = "0.6.8"
+itertools = "0.10.5"
+nom = "7.1.1"
+"#;
+
+const MAIN: &str = r#"use eyre::Result;
+
+fn main() -> Result<()> {
+    aoc_core::run(DAY, dNN::Solution)
+}
+"#;
+
+const TEST: &str = r#"use aoc_core::{Answer, Solver};
+use dNN::Solution;
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+// Paste the example of the statement in the fixture and its answers below
+
+#[test]
+fn part_1() {
+    assert_eq!(Solution.part_1(EXAMPLE).unwrap(), Answer::Unsolved);
+}
+
+#[test]
+fn part_2() {
+    assert_eq!(Solution.part_2(EXAMPLE).unwrap(), Answer::Unsolved);
+}
+"#;
+
+/// Insert `line` among the lines for which `day_of` gives a day, keeping them sorted
+fn insert_line(text: &str, line: &str, day: u8, day_of: impl Fn(&str) -> Option<u8>) -> Result<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let Some(last) = lines.iter().rposition(|l| day_of(l).is_some()) else {
+        bail!("no day listed to insert `{}` next to", line.trim());
+    };
+    let at = lines
+        .iter()
+        .position(|l| day_of(l).is_some_and(|d| d > day))
+        .unwrap_or(last + 1);
+
+    let mut out: Vec<&str> = lines[..at].to_vec();
+    out.push(line);
+    out.extend(&lines[at..]);
+    Ok(out.join("\n") + "\n")
+}
+
+/// Day of a `dNN` name at the start of `s`, after `prefix`
+fn day_after(s: &str, prefix: &str) -> Option<u8> {
+    let s = s.trim_start().strip_prefix(prefix)?.strip_prefix('d')?;
+    s.get(..2)?.parse().ok()
+}
+
+fn edit(path: &Path, f: impl FnOnce(&str) -> Result<String>) -> Result<()> {
+    let text = fs::read_to_string(path).wrap_err_with(|| format!("cannot read {}", path.display()))?;
+    fs::write(path, f(&text)?).wrap_err_with(|| format!("cannot write {}", path.display()))?;
+    println!("Updated {}", path.display());
+    Ok(())
+}
+
+pub fn new(day: u8) -> Result<()> {
+    let root = aoc_core::input::workspace_dir();
+    let name = format!("d{day:02}");
+    let dir = days::day_dir(day);
+    if dir.exists() {
+        bail!("{} already exists, not overwriting it", dir.display());
+    }
+
+    let template = root.join("template.rs");
+    let lib = fs::read_to_string(&template).wrap_err_with(|| format!("cannot read {}", template.display()))?;
+
+    fs::create_dir_all(dir.join("src"))?;
+    fs::create_dir_all(dir.join("tests"))?;
+    fs::create_dir_all(dir.join("fixtures"))?;
+    fs::write(dir.join("Cargo.toml"), MANIFEST.replace("dNN", &name))?;
+    fs::write(dir.join("src/lib.rs"), lib)?;
+    fs::write(dir.join("src/main.rs"), MAIN.replace("dNN", &name).replace("DAY", &day.to_string()))?;
+    fs::write(dir.join("tests/example.rs"), TEST.replace("dNN", &name))?;
+    fs::write(dir.join("fixtures/example.txt"), "")?;
+    println!("Created {}", dir.display());
+
+    edit(&root.join("Cargo.toml"), |text| {
+        insert_line(text, &format!("    \"{name}\","), day, |l| day_after(l, "\""))
+    })?;
+    edit(&root.join("aoc/Cargo.toml"), |text| {
+        insert_line(text, &format!("{name} = {{ path = \"../{name}\" }}"), day, |l| day_after(l, ""))
+    })?;
+    edit(&root.join("aoc/src/days.rs"), |text| {
+        let arm = format!("        {day} => Box::new({name}::Solution),");
+        let text = insert_line(text, &arm, day, |l| {
+            l.trim_start().split_once(" => Box::new(").and_then(|(d, _)| d.parse().ok())
+        })?;
+        Ok(text.replace(
+            &format!("pub const LAST: u8 = {};", days::LAST),
+            &format!("pub const LAST: u8 = {};", days::LAST.max(day)),
+        ))
+    })?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_sorted() {
+        let text = "[\n    \"aoc\",\n    \"d01\",\n    \"d03\",\n]";
+        let day = |l: &str| day_after(l, "\"");
+        assert_eq!(
+            insert_line(text, "    \"d02\",", 2, day).unwrap(),
+            "[\n    \"aoc\",\n    \"d01\",\n    \"d02\",\n    \"d03\",\n]\n"
+        );
+        assert_eq!(
+            insert_line(text, "    \"d04\",", 4, day).unwrap(),
+            "[\n    \"aoc\",\n    \"d01\",\n    \"d03\",\n    \"d04\",\n]\n"
+        );
+    }
+}
@@ -0,0 +1,171 @@
+//! Regression check of the solvers against the answers recorded in `dNN/answers.toml`
+//!
+//! Sections are the names of the inputs, see [`aoc_core::input`].
+//!
+//! ```toml
+//! [real]
+//! part_1 = 24000
+//! part_2 = 45000
+//!
+//! [example]
+//! args = ["--row", "10"]
+//! part_1 = 26
+//! ```
+//!
+//! `args` sets the puzzle parameters the input needs, like `aoc run 15 -- --row 10` would.
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::panic::{self, AssertUnwindSafe};
+
+use aoc::days;
+use aoc_core::{Answer, Solver};
+use eyre::{bail, Result};
+use serde::Deserialize;
+
+/// Answer as written in the answers file, either a bare number or a string
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Recorded {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Recorded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Recorded::Number(n) => write!(f, "{n}"),
+            Recorded::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Expected {
+    #[serde(default)]
+    args: Vec<String>,
+    part_1: Option<Recorded>,
+    part_2: Option<Recorded>,
+}
+
+enum Outcome {
+    Pass,
+    Mismatch { expected: String, actual: String },
+    Fail(String),
+}
+
+/// Run a part catching panics, so that a broken solver does not stop the whole check
+fn solve(solver: &dyn Solver, part: u8, input: &str) -> Result<Answer, String> {
+    panic::catch_unwind(AssertUnwindSafe(|| match part {
+        1 => solver.part_1(input),
+        _ => solver.part_2(input),
+    }))
+    .map_err(|e| {
+        e.downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| e.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "solver panicked".to_string())
+    })?
+    .map_err(|e| format!("{e:#}"))
+}
+
+fn check(solver: &dyn Solver, part: u8, input: &str, expected: &Recorded) -> Outcome {
+    match solve(solver, part, input) {
+        Ok(answer) => {
+            let (expected, actual) = (expected.to_string(), answer.to_string());
+            if expected.trim_end() == actual.trim_end() {
+                Outcome::Pass
+            } else {
+                Outcome::Mismatch { expected, actual }
+            }
+        }
+        Err(msg) => Outcome::Fail(msg),
+    }
+}
+
+/// Line by line diff of the printed answers
+fn print_diff(expected: &str, actual: &str) {
+    let (exp, act): (Vec<_>, Vec<_>) = (expected.lines().collect(), actual.lines().collect());
+    for i in 0..exp.len().max(act.len()) {
+        match (exp.get(i), act.get(i)) {
+            (Some(e), Some(a)) if e == a => println!("      {e}"),
+            (e, a) => {
+                if let Some(e) = e {
+                    println!("    - {e}");
+                }
+                if let Some(a) = a {
+                    println!("    + {a}");
+                }
+            }
+        }
+    }
+}
+
+pub fn verify(days: Vec<u8>, part: Option<u8>) -> Result<()> {
+    let (mut passed, mut failed) = (0, 0);
+    // Only the report goes to the terminal, not the diagnostics of the solvers
+    aoc_core::set_quiet(true);
+
+    // Panics are reported as failures, keep the default hook from cluttering the output
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    for day in days {
+        let dir = days::day_dir(day);
+        let Ok(answers) = std::fs::read_to_string(dir.join("answers.toml")) else {
+            println!("Day {day:02}: no recorded answers");
+            continue;
+        };
+        let answers: BTreeMap<String, Expected> = toml::from_str(&answers)?;
+
+        for (name, expected) in answers {
+            let Some(mut solver) = days::solver(day) else {
+                println!("Day {day:02}: no solver");
+                break;
+            };
+            let input = aoc_core::input::read(day, &name)
+                .map_err(|e| format!("{e:#}"))
+                .and_then(|input| {
+                    solver.configure(&expected.args).map_err(|e| format!("{e:#}"))?;
+                    Ok(input)
+                });
+            let parts = [(1, &expected.part_1), (2, &expected.part_2)];
+            for (p, expected) in parts {
+                let Some(expected) = expected else { continue };
+                if part.is_some_and(|part| part != p) {
+                    continue;
+                }
+
+                let outcome = match &input {
+                    Ok(input) => check(solver.as_ref(), p, input, expected),
+                    Err(e) => Outcome::Fail(e.clone()),
+                };
+
+                let label = format!("Day {day:02} {name} part {p}");
+                match outcome {
+                    Outcome::Pass => {
+                        passed += 1;
+                        println!("PASS     {label}");
+                    }
+                    Outcome::Mismatch { expected, actual } => {
+                        failed += 1;
+                        println!("MISMATCH {label}");
+                        print_diff(&expected, &actual);
+                    }
+                    Outcome::Fail(msg) => {
+                        failed += 1;
+                        println!("FAIL     {label}: {msg}");
+                    }
+                }
+            }
+        }
+    }
+
+    panic::set_hook(hook);
+
+    println!("\n{passed} passed, {failed} failed");
+    if failed > 0 {
+        bail!("{failed} checks failed");
+    }
+    Ok(())
+}
@@ -0,0 +1,106 @@
+//! Puzzle inputs kept on disk, so that everything works offline
+//!
+//! A day can have several named inputs. The `real` input of day 5 is `inputs/2022/05.txt`, any
+//! other name is `inputs/2022/05-<name>.txt`, falling back to the checked-in fixtures of the day,
+//! `d05/fixtures/<name>.txt`. Set `AOC_INPUTS` to look in another directory than `inputs/`.
+
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+
+use eyre::{Result, WrapErr};
+
+pub const YEAR: u16 = 2022;
+
+/// Name of the personal puzzle input, used when none is given
+pub const REAL: &str = "real";
+
+/// Root of the workspace holding the day crates
+pub fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf()
+}
+
+/// Directory of the inputs, `$AOC_INPUTS` or `inputs/` in the workspace
+pub fn inputs_dir() -> PathBuf {
+    std::env::var_os("AOC_INPUTS")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| workspace_dir().join("inputs"))
+}
+
+/// Files where an input can be, in lookup order
+fn candidates(inputs: &Path, day: u8, name: &str) -> Vec<PathBuf> {
+    let file = match name {
+        REAL => format!("{day:02}.txt"),
+        _ => format!("{day:02}-{name}.txt"),
+    };
+    vec![
+        inputs.join(YEAR.to_string()).join(file),
+        workspace_dir().join(format!("d{day:02}/fixtures/{name}.txt")),
+    ]
+}
+
+/// None of the files where an input can be exists
+#[derive(Debug)]
+pub struct MissingInput {
+    pub day: u8,
+    pub name: String,
+    pub tried: Vec<PathBuf>,
+}
+
+impl Display for MissingInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no `{}` input for day {:02}, looked for", self.name, self.day)?;
+        for path in &self.tried {
+            write!(f, "\n  {}", path.display())?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for MissingInput {}
+
+/// Path of the input `name` of `day`
+pub fn resolve(day: u8, name: &str) -> Result<PathBuf, MissingInput> {
+    let tried = candidates(&inputs_dir(), day, name);
+    match tried.iter().find(|p| p.is_file()) {
+        Some(path) => Ok(path.clone()),
+        None => Err(MissingInput {
+            day,
+            name: name.to_string(),
+            tried,
+        }),
+    }
+}
+
+/// Read the input `name` of `day`
+pub fn read(day: u8, name: &str) -> Result<String> {
+    let path = resolve(day, name)?;
+    std::fs::read_to_string(&path).wrap_err_with(|| format!("cannot read {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup_order() {
+        let tried = candidates(Path::new("inputs"), 5, REAL);
+        assert_eq!(tried[0], Path::new("inputs/2022/05.txt"));
+        assert!(tried[1].ends_with("d05/fixtures/real.txt"));
+
+        let tried = candidates(Path::new("inputs"), 9, "example-larger");
+        assert_eq!(tried[0], Path::new("inputs/2022/09-example-larger.txt"));
+        assert!(tried[1].ends_with("d09/fixtures/example-larger.txt"));
+    }
+
+    #[test]
+    fn missing() {
+        let e = resolve(1, "alt-account").unwrap_err();
+        assert_eq!(e.tried.len(), 2);
+        assert!(e.to_string().starts_with("no `alt-account` input for day 01, looked for\n  "));
+    }
+
+    #[test]
+    fn fixture_fallback() {
+        assert!(resolve(1, "example").unwrap().ends_with("d01/fixtures/example.txt"));
+    }
+}
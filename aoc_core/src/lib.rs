@@ -0,0 +1,145 @@
+//! Common interface shared by every day's solver and the `aoc` runner
+
+use std::fmt::{self, Display};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use clap::Parser;
+use eyre::{ensure, eyre, Result};
+
+use options::Options;
+use output::Format;
+
+pub mod input;
+pub mod options;
+pub mod output;
+pub mod parse;
+
+/// Result of one part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    Unsolved,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::Number(value as i64)
+            }
+        })*
+    };
+}
+
+answer_from_int!(usize, isize, i64, i32, u32);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// A day of the calendar: both parts take the raw puzzle input
+pub trait Solver {
+    /// Only parse the input, to time parsing apart from solving
+    fn parse(&self, input: &str) -> Result<()>;
+    fn part_1(&self, input: &str) -> Result<Answer>;
+    fn part_2(&self, input: &str) -> Result<Answer>;
+
+    /// Override the puzzle parameters from command line arguments, such as `--row 10`
+    fn configure(&mut self, args: &[String]) -> Result<()> {
+        ensure!(args.is_empty(), "unexpected arguments {args:?}, this day has no parameters");
+        Ok(())
+    }
+}
+
+/// Update the `clap` parameter struct of a day from command line arguments
+///
+/// Only the given arguments are changed. Asking for `--help` prints it and exits.
+pub fn configure(params: &mut impl Parser, args: &[String]) -> Result<()> {
+    let args = std::iter::once("").chain(args.iter().map(String::as_str));
+    match params.try_update_from(args) {
+        Ok(()) => Ok(()),
+        Err(e) if e.use_stderr() => {
+            let msg = e.to_string();
+            Err(eyre!("{}", msg.trim_start_matches("error: ").trim_end()))
+        }
+        Err(e) => e.exit(),
+    }
+}
+
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Silence the diagnostics printed by the solvers with [`debug!`]
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+pub fn is_quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+/// `eprintln!` for diagnostics of the solvers, unless they were silenced with [`set_quiet`]
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if !$crate::is_quiet() {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Read the whole puzzle input from stdin
+pub fn read_input() -> Result<String> {
+    Ok(std::io::read_to_string(std::io::stdin())?)
+}
+
+/// Print the answers of both parts, one per line
+pub fn print_answers(solver: &impl Solver, input: &str) -> Result<()> {
+    println!("{}", solver.part_1(input)?);
+    println!("{}", solver.part_2(input)?);
+    Ok(())
+}
+
+/// Print the answers of both parts in the chosen format
+pub fn report(day: u8, solver: &impl Solver, input: &str, format: Format) -> Result<()> {
+    match format {
+        Format::Text => print_answers(solver, input),
+        Format::Json => {
+            let solved = output::solve(solver, input, &[1, 2]);
+            println!("{}", output::json(day, &solved));
+            match solved.into_iter().find_map(|s| s.answer.err()) {
+                Some(e) => Err(e),
+                None => Ok(()),
+            }
+        }
+    }
+}
+
+/// Entry point of the `dNN` binaries, the arguments pick the input, the output and set the
+/// puzzle parameters
+pub fn run(day: u8, mut solver: impl Solver) -> Result<()> {
+    let mut args = std::env::args().skip(1).collect();
+    let options = Options::take(&mut args)?;
+    set_quiet(options.quiet);
+    let input = options.read_input(day)?;
+    solver.configure(&args)?;
+    report(day, &solver, &input, options.format)
+}
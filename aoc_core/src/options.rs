@@ -0,0 +1,81 @@
+//! Options understood by every `dNN` binary, taken out of the arguments before the puzzle
+//! parameters of the day
+
+use std::io::IsTerminal;
+
+use eyre::{bail, eyre, Result};
+
+use crate::input::{self, REAL};
+use crate::output::Format;
+
+#[derive(Debug, Default)]
+pub struct Options {
+    /// `--input <name>`
+    pub input: Option<String>,
+    /// `--format <text|json>`
+    pub format: Format,
+    /// `--quiet`, silence the diagnostics of the solver
+    pub quiet: bool,
+}
+
+/// Remove `--flag <value>` or `--flag=<value>` from `args`
+fn take_value(args: &mut Vec<String>, flag: &str) -> Result<Option<String>> {
+    let Some(i) = args.iter().position(|a| a == flag || a.starts_with(&format!("{flag}="))) else {
+        return Ok(None);
+    };
+    let arg = args.remove(i);
+    match arg.strip_prefix(&format!("{flag}=")) {
+        Some(value) => Ok(Some(value.to_string())),
+        None if i < args.len() => Ok(Some(args.remove(i))),
+        None => bail!("`{flag}` needs a value"),
+    }
+}
+
+/// Remove `flag` from `args`, telling whether it was there
+fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    let len = args.len();
+    args.retain(|a| a != flag);
+    args.len() != len
+}
+
+impl Options {
+    /// Take the shared options out of `args`, leaving the puzzle parameters
+    pub fn take(args: &mut Vec<String>) -> Result<Self> {
+        let format = match take_value(args, "--format")? {
+            Some(f) => clap::ValueEnum::from_str(&f, true).map_err(|_| eyre!("unknown format `{f}`, expected `text` or `json`"))?,
+            None => Format::Text,
+        };
+        Ok(Self {
+            input: take_value(args, "--input")?,
+            format,
+            quiet: take_flag(args, "--quiet") | take_flag(args, "-q"),
+        })
+    }
+
+    /// The input named by `--input`, otherwise stdin when it is piped, otherwise the real input
+    pub fn read_input(&self, day: u8) -> Result<String> {
+        match &self.input {
+            Some(name) => input::read(day, name),
+            None if !std::io::stdin().is_terminal() => crate::read_input(),
+            None => input::read(day, REAL),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn take() {
+        let mut args = ["--row", "10", "--input=example", "-q", "--format", "json"].map(String::from).to_vec();
+        let options = Options::take(&mut args).unwrap();
+        assert_eq!(options.input.as_deref(), Some("example"));
+        assert_eq!(options.format, Format::Json);
+        assert!(options.quiet);
+        assert_eq!(args, ["--row", "10"]);
+
+        let mut args = vec!["--input".to_string()];
+        assert!(Options::take(&mut args).is_err());
+    }
+}
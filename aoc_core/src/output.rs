@@ -0,0 +1,106 @@
+//! Answers printed for people or, as JSON, for scripts
+
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+use clap::ValueEnum;
+use eyre::Result;
+use serde_json::{json, Map, Value};
+
+use crate::{Answer, Solver};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    #[default]
+    Text,
+    /// One object per day: `{"day":11,"part1":10605,"part2":...,"timings_us":{...}}`
+    Json,
+}
+
+/// Answer of one part and the time it took
+pub struct Solved {
+    pub part: u8,
+    pub answer: Result<Answer>,
+    pub time: Duration,
+}
+
+/// Solve the given parts, timing each of them
+pub fn solve(solver: &dyn Solver, input: &str, parts: &[u8]) -> Vec<Solved> {
+    parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => solver.part_1(input),
+                _ => solver.part_2(input),
+            };
+            Solved {
+                part,
+                answer,
+                time: start.elapsed(),
+            }
+        })
+        .collect()
+}
+
+fn answer_json(answer: &Answer) -> Value {
+    match answer {
+        Answer::Number(n) => json!(n),
+        Answer::Text(s) => json!(s),
+        Answer::Unsolved => Value::Null,
+    }
+}
+
+/// JSON report of a day on one line, failed parts are `null` with their message in `errors`
+pub fn json(day: u8, solved: &[Solved]) -> String {
+    let mut report = Map::new();
+    let mut timings = Map::new();
+    let mut errors = Map::new();
+    report.insert("day".to_string(), json!(day));
+
+    for s in solved {
+        let key = format!("part{}", s.part);
+        let value = match &s.answer {
+            Ok(answer) => answer_json(answer),
+            Err(e) => {
+                let mut msg = String::new();
+                write!(msg, "{e:#}").unwrap();
+                errors.insert(key.clone(), json!(msg));
+                Value::Null
+            }
+        };
+        report.insert(key.clone(), value);
+        timings.insert(key, json!(s.time.as_micros() as u64));
+    }
+
+    report.insert("timings_us".to_string(), Value::Object(timings));
+    if !errors.is_empty() {
+        report.insert("errors".to_string(), Value::Object(errors));
+    }
+    Value::Object(report).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_report() {
+        let solved = [
+            Solved {
+                part: 1,
+                answer: Ok(Answer::Number(10605)),
+                time: Duration::from_micros(12),
+            },
+            Solved {
+                part: 2,
+                answer: Err(eyre::eyre!("no monkeys")),
+                time: Duration::from_micros(3),
+            },
+        ];
+        assert_eq!(
+            json(11, &solved),
+            r#"{"day":11,"errors":{"part2":"no monkeys"},"part1":10605,"part2":null,"timings_us":{"part1":12,"part2":3}}"#
+        );
+    }
+}
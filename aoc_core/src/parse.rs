@@ -0,0 +1,224 @@
+//! Helpers to report malformed puzzle inputs
+//!
+//! Parsers return a [`ParseError`] pointing at where they stopped. Positions are relative to the
+//! text the error was built from, [`rebase`] (or [`lines`]) moves them to the whole input.
+
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use eyre::{Report, Result};
+use nom::error::{VerboseError, VerboseErrorKind};
+use nom::{Finish, IResult};
+
+/// Result of the `nom` parsers, their errors can be turned into a [`ParseError`]
+pub type NomResult<'a, T> = IResult<&'a str, T, VerboseError<&'a str>>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+/// Byte offset of `slice` inside `base`
+fn offset(base: &str, slice: &str) -> usize {
+    let offset = (slice.as_ptr() as usize).wrapping_sub(base.as_ptr() as usize);
+    assert!(offset <= base.len(), "slice is not part of the parsed text");
+    offset
+}
+
+/// 1-based line and column of a byte offset
+fn position(base: &str, offset: usize) -> (usize, usize) {
+    let before = &base[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = before[before.rfind('\n').map_or(0, |i| i + 1)..].chars().count() + 1;
+    (line, column)
+}
+
+impl ParseError {
+    /// Error at `rest`, which must be a slice of `input` starting where parsing stopped
+    pub fn new(input: &str, rest: &str, expected: impl Display) -> Self {
+        let (line, column) = position(input, offset(input, rest));
+        let found = if rest.is_empty() && input.contains('\n') {
+            "end of input".to_string()
+        } else if rest.is_empty() || rest.starts_with('\n') {
+            "end of line".to_string()
+        } else {
+            // Next token, along with the spaces before it
+            let line = rest.lines().next().unwrap_or_default();
+            let start = line.len() - line.trim_start().len();
+            let end = line[start..].find(char::is_whitespace).map_or(line.len(), |i| start + i);
+            format!("`{}`", &line[..end])
+        };
+        Self {
+            line,
+            column,
+            expected: expected.to_string(),
+            found,
+        }
+    }
+
+    /// Convert the error of a `nom` parser run on `input`
+    ///
+    /// The innermost failure gives the position, the closest `context` the expected token.
+    pub fn from_nom(input: &str, e: VerboseError<&str>) -> Self {
+        let rest = e.errors.first().map_or(input, |(rest, _)| rest);
+        let expected = e
+            .errors
+            .iter()
+            .find_map(|(_, kind)| match kind {
+                VerboseErrorKind::Context(ctx) => Some(ctx.to_string()),
+                _ => None,
+            })
+            .or_else(|| {
+                e.errors.first().map(|(_, kind)| match kind {
+                    VerboseErrorKind::Char(c) => format!("`{c}`"),
+                    VerboseErrorKind::Nom(kind) => kind.description().to_lowercase(),
+                    VerboseErrorKind::Context(ctx) => ctx.to_string(),
+                })
+            })
+            .unwrap_or_else(|| "valid input".to_string());
+        Self::new(input, rest, expected)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Move the position of an error raised parsing `slice` to the `base` text containing it
+pub fn rebase(base: &str, slice: &str, mut e: Report) -> Report {
+    let (line, column) = position(base, offset(base, slice));
+    match e.downcast_mut::<ParseError>() {
+        Some(pe) => {
+            if pe.line == 1 {
+                pe.column += column - 1;
+            }
+            pe.line += line - 1;
+            e
+        }
+        None => e.wrap_err(format!("line {line}")),
+    }
+}
+
+/// Parse every line of `input`, errors point to the position in the whole input
+pub fn lines<'a, T>(
+    input: &'a str,
+    mut f: impl FnMut(&'a str) -> Result<T> + 'a,
+) -> impl Iterator<Item = Result<T>> + 'a {
+    input
+        .lines()
+        .map(move |l| f(l).map_err(|e| rebase(input, l, e)))
+}
+
+/// Parse a whole `token` of `input` as a number
+pub fn number<T: FromStr>(input: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::new(input, token, "a number"))
+}
+
+/// Strip `prefix` from `s`, a slice of `input`
+pub fn tag<'a>(input: &str, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(input, s, format!("`{prefix}`")))
+}
+
+/// Split `s`, a slice of `input`, around the first `sep`
+pub fn split_once<'a>(input: &str, s: &'a str, sep: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(sep)
+        .ok_or_else(|| ParseError::new(input, &s[s.len()..], format!("`{sep}`")))
+}
+
+/// Run a `nom` parser on a whole line
+pub fn nom_line<'a, T>(
+    line: &'a str,
+    mut parser: impl FnMut(&'a str) -> NomResult<'a, T>,
+) -> Result<T, ParseError> {
+    match parser(line).finish() {
+        Ok((rest, v)) => end(line, rest).map(|_| v),
+        Err(e) => Err(ParseError::from_nom(line, e)),
+    }
+}
+
+/// Check that nothing is left after parsing
+pub fn end(input: &str, rest: &str) -> Result<(), ParseError> {
+    if rest.is_empty() {
+        Ok(())
+    } else {
+        Err(ParseError::new(input, rest, "end of line"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use eyre::eyre;
+    use nom::character::complete::{char, digit1};
+    use nom::error::context;
+
+    use super::*;
+
+    fn digits(s: &str) -> NomResult<'_, &str> {
+        context("a digit", digit1)(s)
+    }
+
+    #[test]
+    fn found() {
+        let input = "move 1 from x";
+        let e = ParseError::new(input, &input[12..], "a stack number");
+        assert_eq!(e.to_string(), "line 1, column 13: expected a stack number, found `x`");
+        let e = ParseError::new(input, &input[6..], "a number");
+        assert_eq!(e.found, "` from`");
+
+        let input = "a\nb";
+        assert_eq!(ParseError::new(input, &input[1..], "`c`").found, "end of line");
+        let e = ParseError::new(input, &input[3..], "`c`");
+        assert_eq!((e.line, e.column, e.found.as_str()), (2, 2, "end of input"));
+    }
+
+    #[test]
+    fn rebased() {
+        let base = "ab cd";
+        let slice = &base[3..];
+        let e = rebase(base, slice, ParseError::new(slice, &slice[1..], "`x`").into());
+        assert_eq!(e.to_string(), "line 1, column 5: expected `x`, found `d`");
+
+        // Only errors on the first line of the slice move sideways
+        let base = "x\ny\nz";
+        let slice = &base[2..];
+        let e = rebase(base, slice, ParseError::new(slice, &slice[2..], "`y`").into());
+        assert_eq!(e.to_string(), "line 3, column 1: expected `y`, found `z`");
+
+        let e = rebase(base, slice, eyre!("boom"));
+        assert_eq!(format!("{e:#}"), "line 2: boom");
+    }
+
+    #[test]
+    fn nom() {
+        let e = nom_line("ab", digits).unwrap_err();
+        assert_eq!(e.to_string(), "line 1, column 1: expected a digit, found `ab`");
+        let e = nom_line("12 z", digits).unwrap_err();
+        assert_eq!(e.to_string(), "line 1, column 3: expected end of line, found ` z`");
+        let e = nom_line("y1", |s| char('x')(s)).unwrap_err();
+        assert_eq!(e.expected, "`x`");
+    }
+
+    #[test]
+    fn every_line() {
+        let parsed: Vec<_> = lines("1\n2\nx", |l| Ok(number::<u32>(l, l)?)).collect();
+        assert_eq!(parsed[1].as_ref().unwrap(), &2);
+        let e = parsed[2].as_ref().unwrap_err();
+        assert_eq!(e.to_string(), "line 3, column 1: expected a number, found `x`");
+        let e = split_once("a-b", "a-b", " to ").unwrap_err();
+        assert_eq!(e.to_string(), "line 1, column 4: expected ` to `, found end of line");
+    }
+}
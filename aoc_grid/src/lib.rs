@@ -0,0 +1,319 @@
+//! 2D grids of the character drawings found in the puzzle inputs
+//!
+//! Positions are always `(row, col)`, row 0 being the first line of the text.
+
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use aoc_core::parse::ParseError;
+use eyre::{bail, Result};
+use ndarray::{Array2, ArrayView1};
+
+pub type Pos = (usize, usize);
+
+/// Offset `(d_row, d_col)` between two positions
+pub type Dir = (isize, isize);
+
+/// Up, right, down, left
+pub const DIRS_4: [Dir; 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Clockwise from up, diagonals included
+pub const DIRS_8: [Dir; 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Array2<T>,
+}
+
+impl<T> Grid<T> {
+    /// Parse one cell per character, every line must have the same length
+    ///
+    /// Characters for which `f` returns `None` are reported as not being `expected`.
+    pub fn parse(s: &str, expected: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        let lines: Vec<&str> = s.lines().collect();
+        let Some(first) = lines.first() else {
+            bail!("empty grid");
+        };
+        let width = first.chars().count();
+
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for line in lines.iter() {
+            for (j, (at, c)) in line.char_indices().enumerate() {
+                if j == width {
+                    bail!(ParseError::new(s, &line[at..], "end of line"));
+                }
+                cells.push(f(c).ok_or_else(|| ParseError::new(s, &line[at..], expected))?);
+            }
+            let len = line.chars().count();
+            if len < width {
+                let missing = width - len;
+                let expected =
+                    format!("{missing} more cell{}", if missing == 1 { "" } else { "s" });
+                // From the end of the line in `s`, so that the newline reads as the end of the line
+                let end = line.as_ptr() as usize - s.as_ptr() as usize + line.len();
+                bail!(ParseError::new(s, &s[end..], expected));
+            }
+        }
+
+        Ok(Self {
+            cells: Array2::from_shape_vec((lines.len(), width), cells)?,
+        })
+    }
+
+    pub fn from_fn(height: usize, width: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        Self {
+            cells: Array2::from_shape_fn((height, width), |(i, j)| f((i, j))),
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.nrows()
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.ncols()
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.0 < self.height() && pos.1 < self.width()
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.cells.get(pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.cells.get_mut(pos)
+    }
+
+    pub fn row(&self, i: usize) -> ArrayView1<'_, T> {
+        self.cells.row(i)
+    }
+
+    pub fn column(&self, j: usize) -> ArrayView1<'_, T> {
+        self.cells.column(j)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = ArrayView1<'_, T>> {
+        self.cells.rows().into_iter()
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = ArrayView1<'_, T>> {
+        self.cells.columns().into_iter()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// Cells in row-major order together with their position
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells.indexed_iter()
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width();
+        (0..self.height()).flat_map(move |i| (0..width).map(move |j| (i, j)))
+    }
+
+    /// First position in row-major order matching the predicate
+    pub fn position(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.indexed_iter().find(|(_, v)| pred(v)).map(|(p, _)| p)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.map(f),
+        }
+    }
+
+    /// Move one step in a direction, `None` when leaving the grid
+    pub fn step(&self, pos: Pos, dir: Dir) -> Option<Pos> {
+        let i = pos.0.checked_add_signed(dir.0)?;
+        let j = pos.1.checked_add_signed(dir.1)?;
+        self.contains((i, j)).then_some((i, j))
+    }
+
+    /// Orthogonal neighbours inside the grid
+    pub fn neighbours_4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRS_4.into_iter().filter_map(move |d| self.step(pos, d))
+    }
+
+    /// Orthogonal and diagonal neighbours inside the grid
+    pub fn neighbours_8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRS_8.into_iter().filter_map(move |d| self.step(pos, d))
+    }
+
+    /// Positions seen looking from `pos` towards `dir`, up to the edge of the grid (`pos` excluded)
+    pub fn ray(&self, pos: Pos, dir: Dir) -> Ray<'_, T> {
+        Ray {
+            grid: self,
+            pos,
+            dir,
+        }
+    }
+
+    /// Draw the grid back to text, one line per row
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        self.rows()
+            .map(|r| r.iter().map(&mut f).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn from_elem(height: usize, width: usize, v: T) -> Self {
+        Self {
+            cells: Array2::from_elem((height, width), v),
+        }
+    }
+}
+
+impl Grid<char> {
+    pub fn from_text(s: &str) -> Result<Self> {
+        Self::parse(s, "a character", Some)
+    }
+
+    /// Like [`Grid::from_text`], filling short lines with `fill` instead of rejecting them
+    pub fn from_text_padded(s: &str, fill: char) -> Result<Self> {
+        let width = s.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+        let padded = s
+            .lines()
+            .map(|l| {
+                let len = l.chars().count();
+                l.chars()
+                    .chain(std::iter::repeat_n(fill, width - len))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        Self::from_text(&padded)
+    }
+}
+
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(|&c| c))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        &self.cells[pos]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        &mut self.cells[pos]
+    }
+}
+
+/// Iterator returned by [`Grid::ray`]
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    pos: Pos,
+    dir: Dir,
+}
+
+impl<T> Iterator for Ray<'_, T> {
+    type Item = Pos;
+
+    fn next(&mut self) -> Option<Pos> {
+        self.pos = self.grid.step(self.pos, self.dir)?;
+        Some(self.pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(s: &str) -> Result<Grid<u32>> {
+        Grid::parse(s, "a digit", |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn ragged() {
+        let e = digits("123\n12\n123").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 3: expected 1 more cell, found end of line"
+        );
+        let e = digits("123\n1").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 2: expected 2 more cells, found end of input"
+        );
+        let e = digits("123\n1234").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 4: expected end of line, found `4`"
+        );
+        let e = digits("12\n3x").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 2: expected a digit, found `x`"
+        );
+        assert!(digits("").is_err());
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::from_elem(3, 3, 0);
+        let n4 = |p| grid.neighbours_4(p).collect::<Vec<_>>();
+        let n8 = |p| grid.neighbours_8(p).collect::<Vec<_>>();
+
+        assert_eq!(n4((0, 0)), [(0, 1), (1, 0)]);
+        assert_eq!(n4((0, 1)), [(0, 2), (1, 1), (0, 0)]);
+        assert_eq!(n4((2, 2)), [(1, 2), (2, 1)]);
+        assert_eq!(n8((0, 0)), [(0, 1), (1, 1), (1, 0)]);
+        assert_eq!(n8((1, 0)), [(0, 0), (0, 1), (1, 1), (2, 1), (2, 0)]);
+        assert_eq!(n8((1, 1)).len(), 8);
+    }
+
+    #[test]
+    fn ray() {
+        let grid = Grid::from_elem(3, 4, 0);
+        assert_eq!(
+            grid.ray((1, 1), (0, 1)).collect::<Vec<_>>(),
+            [(1, 2), (1, 3)]
+        );
+        assert_eq!(grid.ray((1, 1), (1, -1)).collect::<Vec<_>>(), [(2, 0)]);
+        assert_eq!(grid.ray((0, 0), (-1, 0)).count(), 0);
+    }
+
+    #[test]
+    fn render() {
+        let text = "#.#\n.#.";
+        let grid = Grid::from_text(text).unwrap();
+        assert_eq!((grid.height(), grid.width()), (2, 3));
+        assert_eq!(grid.to_string(), text);
+
+        let lit = grid.map(|&c| c == '#');
+        assert_eq!(lit.render(|&b| if b { 'X' } else { ' ' }), "X X\n X ");
+    }
+
+    #[test]
+    fn padded() {
+        assert!(Grid::from_text("ab\na").is_err());
+        let grid = Grid::from_text_padded("ab\na\n\nabc", '.').unwrap();
+        assert_eq!(grid.to_string(), "ab.\na..\n...\nabc");
+    }
+}
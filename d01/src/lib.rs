@@ -0,0 +1,142 @@
+use std::io::BufRead;
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use aoc_core::{parse, Answer, Solver};
+use clap::Parser;
+use eyre::{eyre, Result};
+
+use report::ReportFormat;
+
+pub mod report;
+
+/// Streaming aggregation of the calories carried by each elf, only keeping the `k` largest totals
+pub struct TopK {
+    k: usize,
+    top: BinaryHeap<Reverse<usize>>,
+    /// Total of the elf being read, `None` between two elves
+    current: Option<usize>,
+    line: usize,
+}
+
+impl TopK {
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            top: BinaryHeap::with_capacity(k + 1),
+            current: None,
+            line: 0,
+        }
+    }
+
+    /// Add the next line of the input, some calories or the empty line after an elf
+    pub fn push_line(&mut self, line: &str) -> Result<()> {
+        self.line += 1;
+        if line.is_empty() {
+            self.end_elf();
+            return Ok(());
+        }
+
+        let calories: usize = parse::number(line, line).map_err(|mut e| {
+            e.line = self.line;
+            e
+        })?;
+        *self.current.get_or_insert(0) += calories;
+        Ok(())
+    }
+
+    fn end_elf(&mut self) {
+        if let Some(total) = self.current.take() {
+            self.top.push(Reverse(total));
+            if self.top.len() > self.k {
+                self.top.pop();
+            }
+        }
+    }
+
+    /// The largest totals, from the largest down
+    pub fn finish(mut self) -> Vec<usize> {
+        self.end_elf();
+        let mut top: Vec<_> = self.top.into_iter().map(|r| r.0).collect();
+        top.sort_unstable_by(|a, b| b.cmp(a));
+        top
+    }
+}
+
+/// Largest `k` totals of the input
+pub fn top_k(input: &str, k: usize) -> Result<Vec<usize>> {
+    let mut top = TopK::new(k);
+    for line in input.lines() {
+        top.push_line(line)?;
+    }
+    Ok(top.finish())
+}
+
+/// Largest `k` totals, reading the input line by line in constant memory
+pub fn stream(mut reader: impl BufRead, k: usize) -> Result<Vec<usize>> {
+    let mut top = TopK::new(k);
+    let mut line = String::new();
+    while reader.read_line(&mut line)? != 0 {
+        top.push_line(line.trim_end_matches(['\n', '\r']))?;
+        line.clear();
+    }
+    Ok(top.finish())
+}
+
+/// Puzzle parameters, the defaults solve the real input
+#[derive(Clone, Debug, Parser)]
+pub struct Params {
+    /// Elves whose totals are summed in part 2
+    #[arg(long, default_value_t = 3)]
+    pub top: usize,
+    /// Print a report of every elf instead of the answers
+    #[arg(long, value_enum)]
+    pub report: Option<ReportFormat>,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self::parse_from([""])
+    }
+}
+
+#[derive(Default)]
+pub struct Solution {
+    pub params: Params,
+}
+
+impl Solution {
+    /// Answers of both parts in a single pass over a reader
+    pub fn stream(&self, reader: impl BufRead) -> Result<(Answer, Answer)> {
+        let top = stream(reader, self.params.top.max(1))?;
+        let max = top.first().ok_or_else(|| eyre!("no elves in the input"))?;
+        let sum: usize = top.iter().take(self.params.top).sum();
+
+        Ok(((*max).into(), sum.into()))
+    }
+}
+
+impl Solver for Solution {
+    fn parse(&self, input: &str) -> Result<()> {
+        top_k(input, 0)?;
+
+        Ok(())
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        let max = *top_k(input, 1)?
+            .first()
+            .ok_or_else(|| eyre!("no elves in the input"))?;
+
+        Ok(max.into())
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        let sum: usize = top_k(input, self.params.top)?.into_iter().sum();
+
+        Ok(sum.into())
+    }
+
+    fn configure(&mut self, args: &[String]) -> Result<()> {
+        aoc_core::configure(&mut self.params, args)
+    }
+}
@@ -1,44 +1,39 @@
-use std::{cmp::Reverse, collections::BinaryHeap, io::stdin};
+use std::io::IsTerminal;
 
+use aoc_core::options::Options;
+use aoc_core::output::Format;
+use aoc_core::{debug, Solver};
+use d01::report::ReportFormat;
 use eyre::Result;
 
 fn main() -> Result<()> {
-    let input = std::io::read_to_string(stdin())?;
-
-    // Part 1
-    let max = input
-        .split("\n\n")
-        .map(|elf| {
-            elf.lines()
-                .map(|l| l.parse::<usize>().unwrap())
-                .sum::<usize>()
-        })
-        .max()
-        .unwrap();
-
-    println!("{max}");
-
-    // Part 2
-    let top = input
-        .split("\n\n")
-        .map(|elf| {
-            elf.lines()
-                .map(|l| l.parse::<usize>().unwrap())
-                .sum::<usize>()
-        })
-        .fold(BinaryHeap::from(vec![Reverse(0)]), |mut heap, x| {
-            if x > heap.peek().unwrap().0 {
-                heap.push(Reverse(x));
-            }
-            if heap.len() > 3 {
-                heap.pop();
+    let mut args = std::env::args().skip(1).collect();
+    let options = Options::take(&mut args)?;
+    aoc_core::set_quiet(options.quiet);
+    let mut solver = d01::Solution::default();
+    solver.configure(&args)?;
+
+    if let Some(format) = solver.params.report {
+        let input = options.read_input(1)?;
+        let report = d01::report::Report::new(&input);
+        match format {
+            ReportFormat::Table => println!("{report}"),
+            ReportFormat::Csv => {
+                print!("{}", report.csv());
+                debug!("{}", report.summary());
             }
-            heap
-        });
-
-    let max = top.into_iter().map(|r| r.0).sum::<usize>();
-
-    println!("{max}");
-
-    Ok(())
+        }
+        return Ok(());
+    }
+
+    // A piped input is aggregated while it is read, so that it does not have to fit in memory
+    if options.input.is_none() && options.format == Format::Text && !std::io::stdin().is_terminal() {
+        let (max, sum) = solver.stream(std::io::stdin().lock())?;
+        println!("{max}");
+        println!("{sum}");
+        return Ok(());
+    }
+
+    let input = options.read_input(1)?;
+    aoc_core::report(1, &solver, &input, options.format)
 }
@@ -0,0 +1,159 @@
+//! Per-elf inventory report, for checking generated or hand-edited inputs
+//!
+//! Unlike the solver, the report does not stop at the first malformed line: non-numeric lines and
+//! empty groups are collected and listed after the statistics.
+
+use std::fmt::{self, Display};
+
+use aoc_core::parse::{self, ParseError};
+use clap::ValueEnum;
+
+/// Percentiles listed in the statistics, the median is always listed
+const PERCENTILES: [usize; 4] = [10, 25, 75, 90];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ReportFormat {
+    Table,
+    Csv,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Elf {
+    /// 1-based position of the elf in the input
+    pub index: usize,
+    /// Line of the first item of the elf
+    pub line: usize,
+    pub items: usize,
+    pub total: usize,
+    /// 1-based rank by total, elves with the same total share a rank
+    pub rank: usize,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Report {
+    pub elves: Vec<Elf>,
+    /// Lines of the blank lines that do not end an elf
+    pub empty_groups: Vec<usize>,
+    /// Lines which are not a number, they are left out of the totals
+    pub invalid: Vec<ParseError>,
+}
+
+impl Report {
+    pub fn new(input: &str) -> Self {
+        let mut report = Self::default();
+        let mut current: Option<Elf> = None;
+
+        for (i, l) in input.lines().enumerate() {
+            let line = i + 1;
+            if l.is_empty() {
+                match current.take() {
+                    Some(elf) => report.elves.push(elf),
+                    None => report.empty_groups.push(line),
+                }
+                continue;
+            }
+
+            let elf = current.get_or_insert(Elf {
+                index: report.elves.len() + 1,
+                line,
+                items: 0,
+                total: 0,
+                rank: 0,
+            });
+            match parse::number::<usize>(l, l) {
+                Ok(calories) => {
+                    elf.items += 1;
+                    elf.total += calories;
+                }
+                Err(mut e) => {
+                    e.line = line;
+                    report.invalid.push(e);
+                }
+            }
+        }
+        report.elves.extend(current);
+
+        let mut totals: Vec<_> = report.elves.iter().map(|e| e.total).collect();
+        totals.sort_unstable_by(|a, b| b.cmp(a));
+        for elf in report.elves.iter_mut() {
+            elf.rank = totals.partition_point(|&t| t > elf.total) + 1;
+        }
+
+        report
+    }
+
+    /// Totals from the smallest up
+    fn sorted_totals(&self) -> Vec<usize> {
+        let mut totals: Vec<_> = self.elves.iter().map(|e| e.total).collect();
+        totals.sort_unstable();
+        totals
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        let sum: usize = self.elves.iter().map(|e| e.total).sum();
+        (!self.elves.is_empty()).then(|| sum as f64 / self.elves.len() as f64)
+    }
+
+    pub fn median(&self) -> Option<f64> {
+        let totals = self.sorted_totals();
+        let n = totals.len();
+        match n {
+            0 => None,
+            _ if n % 2 == 1 => Some(totals[n / 2] as f64),
+            _ => Some((totals[n / 2 - 1] + totals[n / 2]) as f64 / 2.0),
+        }
+    }
+
+    /// Nearest-rank percentile of the totals, `p` between 0 and 100
+    pub fn percentile(&self, p: usize) -> Option<usize> {
+        let totals = self.sorted_totals();
+        let rank = (p * totals.len()).div_ceil(100).max(1);
+        totals.get(rank - 1).copied()
+    }
+
+    pub fn csv(&self) -> String {
+        let mut csv = "elf,line,items,calories,rank\n".to_string();
+        for e in &self.elves {
+            csv += &format!("{},{},{},{},{}\n", e.index, e.line, e.items, e.total, e.rank);
+        }
+        csv
+    }
+
+    /// Statistics and problems found in the input, one per line
+    pub fn summary(&self) -> String {
+        let mut lines = vec![format!("{:<10}{:>10}", "Elves", self.elves.len())];
+        if let (Some(mean), Some(median)) = (self.mean(), self.median()) {
+            let totals = self.sorted_totals();
+            let sum: usize = totals.iter().sum();
+            lines.push(format!("{:<10}{sum:>10}", "Total"));
+            lines.push(format!("{:<10}{:>10}", "Min", totals[0]));
+            lines.push(format!("{:<10}{:>10}", "Max", totals[totals.len() - 1]));
+            lines.push(format!("{:<10}{mean:>10.1}", "Mean"));
+            lines.push(format!("{:<10}{median:>10.1}", "Median"));
+            for p in PERCENTILES {
+                let value = self.percentile(p).unwrap_or_default();
+                lines.push(format!("{:<10}{value:>10}", format!("P{p}")));
+            }
+        }
+
+        for line in &self.empty_groups {
+            lines.push(format!("line {line}: empty group"));
+        }
+        for e in &self.invalid {
+            lines.push(e.to_string());
+        }
+        lines.join("\n")
+    }
+}
+
+impl Display for Report {
+    /// Table of the elves followed by the summary
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:>6}{:>8}{:>8}{:>10}{:>6}", "Elf", "Line", "Items", "Calories", "Rank")?;
+        for e in &self.elves {
+            writeln!(f, "{:>6}{:>8}{:>8}{:>10}{:>6}", e.index, e.line, e.items, e.total, e.rank)?;
+        }
+        writeln!(f)?;
+        write!(f, "{}", self.summary())
+    }
+}
@@ -0,0 +1,31 @@
+use aoc_core::{Answer, Solver};
+use d01::Solution;
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part_1() {
+    assert_eq!(Solution::default().part_1(EXAMPLE).unwrap(), Answer::Number(24000));
+}
+
+#[test]
+fn part_2() {
+    assert_eq!(Solution::default().part_2(EXAMPLE).unwrap(), Answer::Number(45000));
+}
+
+#[test]
+fn top_k() {
+    let mut solution = Solution::default();
+    solution.configure(&["--top".to_string(), "2".to_string()]).unwrap();
+    assert_eq!(solution.part_2(EXAMPLE).unwrap(), Answer::Number(35000));
+    assert_eq!(d01::top_k(EXAMPLE, 10).unwrap(), [24000, 11000, 10000, 6000, 4000]);
+}
+
+#[test]
+fn stream() {
+    let answers = Solution::default().stream(EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(answers, (Answer::Number(24000), Answer::Number(45000)));
+
+    let err = d01::stream("1\n\n2\nx\n".as_bytes(), 3).unwrap_err();
+    assert_eq!(err.to_string(), "line 4, column 1: expected a number, found `x`");
+}
@@ -0,0 +1,28 @@
+use d01::report::Report;
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn statistics() {
+    let report = Report::new(EXAMPLE);
+    let totals: Vec<_> = report.elves.iter().map(|e| (e.index, e.line, e.total, e.rank)).collect();
+    assert_eq!(
+        totals,
+        [(1, 1, 6000, 4), (2, 5, 4000, 5), (3, 7, 11000, 2), (4, 10, 24000, 1), (5, 14, 10000, 3)]
+    );
+    assert_eq!(report.mean(), Some(11000.0));
+    assert_eq!(report.median(), Some(10000.0));
+    assert_eq!(report.percentile(10), Some(4000));
+    assert_eq!(report.percentile(90), Some(24000));
+}
+
+#[test]
+fn problems() {
+    let report = Report::new("1\n2\n\n\n3\nx\n\n3\n");
+    let ranks: Vec<_> = report.elves.iter().map(|e| (e.total, e.items, e.rank)).collect();
+    assert_eq!(ranks, [(3, 2, 1), (3, 1, 1), (3, 1, 1)]);
+    assert_eq!(report.empty_groups, [4]);
+    assert_eq!(report.invalid.len(), 1);
+    assert_eq!(report.invalid[0].to_string(), "line 6, column 1: expected a number, found `x`");
+    assert_eq!(report.csv().lines().nth(2), Some("2,5,1,3,1"));
+}
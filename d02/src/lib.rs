@@ -0,0 +1,215 @@
+use aoc_core::parse::{self, ParseError};
+use aoc_core::{Answer, Solver};
+use clap::Parser;
+use eyre::{bail, ensure, Result};
+
+use optimise::Goal;
+
+pub mod optimise;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+/// Cyclic game of `shapes` shapes, rock-paper-scissors being the game of 3
+///
+/// Each shape beats the half of the other shapes that come just before it in the cycle and loses
+/// to the half just after it, which needs an odd number of shapes.
+#[derive(Clone, Debug)]
+pub struct Rules {
+    shapes: usize,
+    /// Points for playing each shape
+    shape_scores: Vec<usize>,
+    /// Points for a loss, a draw and a win
+    outcome_scores: [usize; 3],
+}
+
+impl Rules {
+    pub fn new(shapes: usize, shape_scores: Vec<usize>, outcome_scores: [usize; 3]) -> Result<Self> {
+        ensure!((3..=25).contains(&shapes), "the number of shapes must be between 3 and 25");
+        ensure!(shapes % 2 == 1, "the number of shapes must be odd, got {shapes}");
+        ensure!(
+            shape_scores.len() == shapes,
+            "expected a score for each of the {shapes} shapes, got {}",
+            shape_scores.len()
+        );
+        Ok(Self { shapes, shape_scores, outcome_scores })
+    }
+
+    /// Rock-paper-scissors-like game where the `i`-th shape is worth `i + 1` points
+    pub fn cyclic(shapes: usize) -> Result<Self> {
+        Self::new(shapes, (1..=shapes).collect(), [0, 3, 6])
+    }
+
+    pub fn shapes(&self) -> usize {
+        self.shapes
+    }
+
+    /// Outcome of playing `b` against `a`
+    pub fn outcome(&self, a: usize, b: usize) -> Outcome {
+        match (b + self.shapes - a) % self.shapes {
+            0 => Outcome::Draw,
+            d if d <= self.shapes / 2 => Outcome::Win,
+            _ => Outcome::Lose,
+        }
+    }
+
+    pub fn outcome_score(&self, outcome: Outcome) -> usize {
+        self.outcome_scores[outcome as usize]
+    }
+
+    /// Score of playing `b` against `a`
+    pub fn score(&self, (a, b): (usize, usize)) -> usize {
+        self.outcome_score(self.outcome(a, b)) + self.shape_scores[b]
+    }
+
+    /// Shape `offset` steps away from `a` in the cycle, a win when positive and a loss when negative
+    pub fn shape_at(&self, a: usize, offset: isize) -> usize {
+        (a as isize + offset).rem_euclid(self.shapes as isize) as usize
+    }
+
+    /// Score when the second column is the outcome: the middle letter is a draw, the ones after
+    /// it are wins against the next shapes in the cycle, the ones before it losses
+    pub fn score_2(&self, (a, res): (usize, usize)) -> usize {
+        let offset = res as isize - (self.shapes / 2) as isize;
+        self.score((a, self.shape_at(a, offset)))
+    }
+
+    /// Shapes of the opponent from `A`, second column ending with `Z` (`X`, `Y`, `Z` for 3 shapes)
+    pub fn parse_line(&self, l: &str) -> Result<(usize, usize)> {
+        let (a, b) = parse::split_once(l, l, " ")?;
+        Ok((self.column(l, a, b'A')?, self.column(l, b, self.second_column())?))
+    }
+
+    /// Only the shape of the opponent, anything after it is ignored
+    pub fn parse_opponent(&self, l: &str) -> Result<usize> {
+        let a = l.split(' ').next().unwrap_or_default();
+        Ok(self.column(l, a, b'A')?)
+    }
+
+    /// Index of the letter `s` in a column of letters from `first`
+    fn column(&self, l: &str, s: &str, first: u8) -> Result<usize, ParseError> {
+        let index = match s.as_bytes() {
+            &[c] => (c as usize).checked_sub(first as usize),
+            _ => None,
+        };
+        index
+            .filter(|&i| i < self.shapes)
+            .ok_or_else(|| ParseError::new(l, s, letters(first, self.shapes)))
+    }
+
+    fn second_column(&self) -> u8 {
+        b'Z' + 1 - self.shapes as u8
+    }
+
+    /// Line of a strategy guide where the second column is the shape to play
+    pub fn format_round(&self, (a, b): (usize, usize)) -> String {
+        format!("{} {}", (b'A' + a as u8) as char, (self.second_column() + b as u8) as char)
+    }
+}
+
+/// "`A`, `B` or `C`"
+fn letters(first: u8, n: usize) -> String {
+    let letters: Vec<_> = (first..first + n as u8).map(|c| format!("`{}`", c as char)).collect();
+    format!("{} or {}", letters[..n - 1].join(", "), letters[n - 1])
+}
+
+/// Puzzle parameters, the defaults solve the real input
+#[derive(Clone, Debug, Parser)]
+pub struct Params {
+    /// Number of shapes in the cycle, odd
+    #[arg(long, default_value_t = 3)]
+    pub shapes: usize,
+    /// Points for each shape, 1 for the first shape, 2 for the second... by default
+    #[arg(long, value_delimiter = ',')]
+    pub shape_scores: Vec<usize>,
+    /// Points for a loss, a draw and a win
+    #[arg(long, value_delimiter = ',', default_value = "0,3,6")]
+    pub outcome_scores: Vec<usize>,
+    /// Print the strategy guide reaching the best or worst score against the opponent column,
+    /// then both scores
+    #[arg(long, value_enum)]
+    pub optimise: Option<Goal>,
+    /// Play at most that many wins in the optimised strategy guide
+    #[arg(long, requires = "optimise")]
+    pub max_wins: Option<usize>,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self::parse_from([""])
+    }
+}
+
+#[derive(Default)]
+pub struct Solution {
+    pub params: Params,
+}
+
+impl Solution {
+    pub fn rules(&self) -> Result<Rules> {
+        let Params { shapes, shape_scores, outcome_scores, .. } = &self.params;
+        let Ok(outcome_scores) = outcome_scores[..].try_into() else {
+            bail!("expected 3 outcome scores, got {}", outcome_scores.len());
+        };
+        if shape_scores.is_empty() {
+            Ok(Rules { outcome_scores, ..Rules::cyclic(*shapes)? })
+        } else {
+            Rules::new(*shapes, shape_scores.clone(), outcome_scores)
+        }
+    }
+
+    /// Strategy guide for the opponent column of the input, following `--optimise`
+    pub fn optimise(&self, input: &str, goal: Goal) -> Result<optimise::Plan> {
+        let rules = self.rules()?;
+        let opponents = parse::lines(input, |l| rules.parse_opponent(l)).collect::<Result<Vec<_>>>()?;
+
+        Ok(optimise::optimise(&rules, goal, &opponents, self.params.max_wins))
+    }
+
+    /// Strategy guide reaching `goal`, then the best and worst totals within `--max-wins`
+    pub fn optimise_report(&self, input: &str, goal: Goal) -> Result<String> {
+        let mut report = self.optimise(input, goal)?.guide(&self.rules()?) + "\n";
+        for (name, goal) in [("Best", Goal::Best), ("Worst", Goal::Worst)] {
+            let plan = self.optimise(input, goal)?;
+            let s = if plan.wins == 1 { "" } else { "s" };
+            report += &format!("{name}: {}, with {} win{s}\n", plan.score, plan.wins);
+        }
+
+        Ok(report)
+    }
+}
+
+impl Solver for Solution {
+    fn parse(&self, input: &str) -> Result<()> {
+        let rules = self.rules()?;
+        parse::lines(input, |l| rules.parse_line(l)).collect::<Result<Vec<_>>>()?;
+
+        Ok(())
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        let rules = self.rules()?;
+        let r: usize = parse::lines(input, |l| rules.parse_line(l))
+            .map(|r| r.map(|round| rules.score(round)))
+            .sum::<Result<_>>()?;
+
+        Ok(r.into())
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        let rules = self.rules()?;
+        let r: usize = parse::lines(input, |l| rules.parse_line(l))
+            .map(|r| r.map(|round| rules.score_2(round)))
+            .sum::<Result<_>>()?;
+
+        Ok(r.into())
+    }
+
+    fn configure(&mut self, args: &[String]) -> Result<()> {
+        aoc_core::configure(&mut self.params, args)
+    }
+}
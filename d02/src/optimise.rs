@@ -0,0 +1,76 @@
+//! Strategy guides reaching the best or worst score against a known opponent
+//!
+//! Rounds are independent, so without a limit each round simply takes its best shape. With at
+//! most `k` wins, every round starts from its best shape that does not win, and the `k` rounds
+//! gaining the most by winning instead are switched to their best winning shape.
+
+use clap::ValueEnum;
+
+use crate::{Outcome, Rules};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Goal {
+    Best,
+    Worst,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Plan {
+    /// `(opponent, me)` shapes of every round
+    pub rounds: Vec<(usize, usize)>,
+    pub score: usize,
+    pub wins: usize,
+}
+
+impl Plan {
+    /// Strategy guide in the input format, the second column being the shape to play
+    pub fn guide(&self, rules: &Rules) -> String {
+        self.rounds.iter().map(|&r| rules.format_round(r) + "\n").collect()
+    }
+}
+
+/// Gain of going from `from` to `to` points towards the goal
+fn gain(goal: Goal, from: usize, to: usize) -> isize {
+    match goal {
+        Goal::Best => to as isize - from as isize,
+        Goal::Worst => from as isize - to as isize,
+    }
+}
+
+/// Best shape towards the goal among the ones for which `keep` holds, the lowest one on ties
+fn pick(rules: &Rules, goal: Goal, a: usize, keep: impl Fn(Outcome) -> bool) -> Option<usize> {
+    (0..rules.shapes())
+        .filter(|&b| keep(rules.outcome(a, b)))
+        .fold(None, |best, b| match best {
+            Some(best) if gain(goal, rules.score((a, best)), rules.score((a, b))) <= 0 => Some(best),
+            _ => Some(b),
+        })
+}
+
+pub fn optimise(rules: &Rules, goal: Goal, opponents: &[usize], max_wins: Option<usize>) -> Plan {
+    let mut rounds: Vec<_> = opponents
+        .iter()
+        .map(|&a| (a, pick(rules, goal, a, |o| o != Outcome::Win).expect("a draw is always possible")))
+        .collect();
+
+    // Rounds worth switching to a win, the most useful first
+    let mut switches: Vec<_> = rounds
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &(a, b))| {
+            let win = pick(rules, goal, a, |o| o == Outcome::Win)?;
+            let gain = gain(goal, rules.score((a, b)), rules.score((a, win)));
+            (gain > 0).then_some((gain, i, win))
+        })
+        .collect();
+    switches.sort_by_key(|&(gain, i, _)| (-gain, i));
+    for &(_, i, win) in switches.iter().take(max_wins.unwrap_or(usize::MAX)) {
+        rounds[i].1 = win;
+    }
+
+    Plan {
+        score: rounds.iter().map(|&r| rules.score(r)).sum(),
+        wins: rounds.iter().filter(|&&(a, b)| rules.outcome(a, b) == Outcome::Win).count(),
+        rounds,
+    }
+}
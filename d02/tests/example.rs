@@ -0,0 +1,64 @@
+use aoc_core::{Answer, Solver};
+use d02::optimise::Goal;
+use d02::{Outcome, Solution};
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part_1() {
+    assert_eq!(Solution::default().part_1(EXAMPLE).unwrap(), Answer::Number(15));
+}
+
+#[test]
+fn part_2() {
+    assert_eq!(Solution::default().part_2(EXAMPLE).unwrap(), Answer::Number(12));
+}
+
+#[test]
+fn five_shapes() {
+    let mut solution = Solution::default();
+    solution.configure(&["--shapes".to_string(), "5".to_string()]).unwrap();
+    let rules = solution.rules().unwrap();
+    // Every shape beats the two before it and loses to the two after it
+    assert_eq!(rules.outcome(0, 1), Outcome::Win);
+    assert_eq!(rules.outcome(0, 2), Outcome::Win);
+    assert_eq!(rules.outcome(0, 3), Outcome::Lose);
+    assert_eq!(rules.outcome(0, 4), Outcome::Lose);
+
+    // `V` to `Z` are the shapes, or a big loss to a big win
+    let input = "A V\nE Z\nC X\n";
+    assert_eq!(solution.part_1(input).unwrap(), Answer::Number(4 + 8 + 6));
+    assert_eq!(solution.part_2(input).unwrap(), Answer::Number(4 + 8 + 6));
+
+    let err = solution.part_1("A Y\nF X\n").unwrap_err();
+    assert_eq!(format!("{err:#}"), "line 2, column 1: expected `A`, `B`, `C`, `D` or `E`, found `F`");
+}
+
+#[test]
+fn even_shapes() {
+    let mut solution = Solution::default();
+    solution.configure(&["--shapes".to_string(), "4".to_string()]).unwrap();
+    assert!(solution.part_1(EXAMPLE).is_err());
+}
+
+#[test]
+fn optimise() {
+    let solution = Solution::default();
+    let best = solution.optimise(EXAMPLE, Goal::Best).unwrap();
+    assert_eq!(best.guide(&solution.rules().unwrap()), "A Y\nB Z\nC X\n");
+    assert_eq!((best.score, best.wins), (8 + 9 + 7, 3));
+
+    let worst = solution.optimise(EXAMPLE, Goal::Worst).unwrap();
+    assert_eq!((worst.score, worst.wins), (3 + 1 + 2, 0));
+
+    // Winning against `A` or `B` gains the most, the first round is picked on ties
+    let mut solution = Solution::default();
+    solution.configure(&["--optimise=best".to_string(), "--max-wins=1".to_string()]).unwrap();
+    let plan = solution.optimise(EXAMPLE, Goal::Best).unwrap();
+    assert_eq!(plan.rounds, [(0, 1), (1, 1), (2, 2)]);
+    assert_eq!((plan.score, plan.wins), (8 + 5 + 6, 1));
+    assert_eq!(
+        solution.optimise_report(EXAMPLE, Goal::Worst).unwrap(),
+        "A Z\nB X\nC Y\n\nBest: 19, with 1 win\nWorst: 6, with 0 wins\n"
+    );
+}
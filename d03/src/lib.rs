@@ -0,0 +1,237 @@
+use std::fmt::{self, Display};
+use std::ops::{BitAnd, BitOr};
+
+use aoc_core::parse::{self, ParseError};
+use aoc_core::{debug, Answer, Solver};
+use clap::Parser;
+use eyre::{ensure, eyre, Result};
+
+pub mod partition;
+
+fn char_prio(c: char) -> u8 {
+    match c {
+        'a'..='z' => c as u8 - b'a' + 1,
+        'A'..='Z' => c as u8 - b'A' + 27,
+        _ => unreachable!(),
+    }
+}
+
+fn prio_char(p: u8) -> char {
+    match p {
+        1..=26 => (b'a' + p - 1) as char,
+        _ => (b'A' + p - 27) as char,
+    }
+}
+
+/// Set of items, bit `p` being set when the item of priority `p` is present
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Items(pub u64);
+
+impl Items {
+    /// Every item, the identity of `&`
+    pub const ALL: Items = Items(((1 << 52) - 1) << 1);
+
+    /// Items of a list of item letters
+    pub fn new(s: &str) -> Self {
+        Self(s.chars().fold(0, |set, c| set | 1 << char_prio(c)))
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Priorities of the items, from the lowest
+    pub fn priorities(self) -> impl Iterator<Item = u8> {
+        (1..=52).filter(move |p| self.0 & 1 << p != 0)
+    }
+
+    /// Priority of the item when there is exactly one
+    pub fn single(self) -> Option<u8> {
+        (self.len() == 1).then(|| self.0.trailing_zeros() as u8)
+    }
+}
+
+impl BitAnd for Items {
+    type Output = Items;
+
+    fn bitand(self, rhs: Items) -> Items {
+        Items(self.0 & rhs.0)
+    }
+}
+
+impl BitOr for Items {
+    type Output = Items;
+
+    fn bitor(self, rhs: Items) -> Items {
+        Items(self.0 | rhs.0)
+    }
+}
+
+impl Display for Items {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "no item");
+        }
+        let items: Vec<_> = self
+            .priorities()
+            .map(|p| format!("`{}`", prio_char(p)))
+            .collect();
+        write!(f, "{}", items.join(", "))
+    }
+}
+
+/// Items found in both compartments
+pub fn shared(rucksack: &str) -> Items {
+    let (left, right) = rucksack.split_at(rucksack.len() / 2);
+    Items::new(left) & Items::new(right)
+}
+
+fn check_groups(rucksacks: usize, size: usize) -> Result<()> {
+    ensure!(size > 0, "groups must have at least one elf");
+    ensure!(
+        rucksacks.is_multiple_of(size),
+        "{rucksacks} rucksacks cannot be split into groups of {size}"
+    );
+    Ok(())
+}
+
+/// Items carried by every elf of each group, along with the line of the group's first rucksack
+pub fn badges<'a>(
+    rucksacks: &'a [&str],
+    size: usize,
+) -> Result<impl Iterator<Item = (usize, Items)> + 'a> {
+    check_groups(rucksacks.len(), size)?;
+    Ok(rucksacks.chunks(size).enumerate().map(move |(i, group)| {
+        let badges = group.iter().fold(Items::ALL, |set, r| set & Items::new(r));
+        (i * size + 1, badges)
+    }))
+}
+
+/// Lines of the input, checked to only hold item letters split in two compartments
+fn rucksacks(input: &str) -> Result<Vec<&str>> {
+    parse::lines(input, |l| {
+        match l.find(|c: char| !c.is_ascii_alphabetic()) {
+            Some(i) => Err(ParseError::new(l, &l[i..], "an item letter").into()),
+            None if l.len() % 2 == 1 => {
+                Err(ParseError::new(l, &l[l.len()..], "an even number of items").into())
+            }
+            None => Ok(l),
+        }
+    })
+    .collect()
+}
+
+/// Puzzle parameters, the defaults solve the real input
+#[derive(Clone, Debug, Parser)]
+pub struct Params {
+    /// Elves in each group of part 2
+    #[arg(long, default_value_t = 3)]
+    pub group: usize,
+    /// List the rucksacks and groups which do not share exactly one item instead of the answers
+    #[arg(long)]
+    pub diagnose: bool,
+    /// Find the groups of part 2 among rucksacks in any order, instead of consecutive lines
+    #[arg(long)]
+    pub unordered: bool,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self::parse_from([""])
+    }
+}
+
+#[derive(Default)]
+pub struct Solution {
+    pub params: Params,
+}
+
+impl Solution {
+    /// Every rucksack and group that does not share exactly one item
+    pub fn diagnose(&self, input: &str) -> Result<Vec<String>> {
+        let rucksacks = rucksacks(input)?;
+        let mut problems = Vec::new();
+
+        for (i, r) in rucksacks.iter().enumerate() {
+            let items = shared(r);
+            if items.single().is_none() {
+                problems.push(format!("line {}: compartments share {items}", i + 1));
+            }
+        }
+        for (line, items) in badges(&rucksacks, self.params.group)? {
+            if items.single().is_none() {
+                let last = line + self.params.group - 1;
+                problems.push(format!("lines {line}-{last}: group shares {items}"));
+            }
+        }
+
+        Ok(problems)
+    }
+
+    /// Badge sum of any partition of the rucksacks into groups sharing exactly one item
+    fn unordered(&self, rucksacks: &[&str]) -> Result<Answer> {
+        let size = self.params.group;
+        check_groups(rucksacks.len(), size)?;
+        let items: Vec<_> = rucksacks.iter().map(|r| Items::new(r)).collect();
+        let groups = partition::partition(&items, size).ok_or_else(|| {
+            eyre!("the rucksacks cannot be split into groups of {size} sharing exactly one item")
+        })?;
+
+        let mut r = 0;
+        for group in groups {
+            let lines: Vec<_> = group.iter().map(|i| (i + 1).to_string()).collect();
+            let badge = group.iter().fold(Items::ALL, |set, &i| set & items[i]);
+            debug!("lines {} share {badge}", lines.join(", "));
+            r += badge.single().map_or(0, usize::from);
+        }
+
+        Ok(r.into())
+    }
+}
+
+impl Solver for Solution {
+    fn parse(&self, input: &str) -> Result<()> {
+        rucksacks(input)?;
+
+        Ok(())
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        let mut r = 0;
+        for (i, rucksack) in rucksacks(input)?.into_iter().enumerate() {
+            let items = shared(rucksack);
+            let prio = items
+                .single()
+                .ok_or_else(|| eyre!("line {}: compartments share {items}", i + 1))?;
+            r += usize::from(prio);
+        }
+
+        Ok(r.into())
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        let rucksacks = rucksacks(input)?;
+        if self.params.unordered {
+            return self.unordered(&rucksacks);
+        }
+
+        let mut r = 0;
+        for (line, items) in badges(&rucksacks, self.params.group)? {
+            let prio = items.single().ok_or_else(|| {
+                let last = line + self.params.group - 1;
+                eyre!("lines {line}-{last}: group shares {items}")
+            })?;
+            r += usize::from(prio);
+        }
+
+        Ok(r.into())
+    }
+
+    fn configure(&mut self, args: &[String]) -> Result<()> {
+        aoc_core::configure(&mut self.params, args)
+    }
+}
@@ -0,0 +1,93 @@
+//! Badge groups of rucksacks given in no particular order
+//!
+//! The search always completes the group of the first rucksack left: since every rucksack ends up
+//! in a group, trying each way to complete that group covers every partition. Groups are built one
+//! elf at a time, dropping any group whose shared items run out, and sets of rucksacks already
+//! known to have no partition are remembered.
+
+use std::collections::HashSet;
+
+use crate::Items;
+
+struct Search<'a> {
+    rucksacks: &'a [Items],
+    size: usize,
+    /// Bit `i` of word `i / 64` is set once rucksack `i` is in a group
+    used: Vec<u64>,
+    groups: Vec<Vec<usize>>,
+    dead: HashSet<Vec<u64>>,
+}
+
+impl Search<'_> {
+    fn is_used(&self, i: usize) -> bool {
+        self.used[i / 64] & 1 << (i % 64) != 0
+    }
+
+    fn toggle(&mut self, i: usize) {
+        self.used[i / 64] ^= 1 << (i % 64);
+    }
+
+    /// Group the rucksacks left, `true` when they all found a group
+    fn search(&mut self) -> bool {
+        let Some(first) = (0..self.rucksacks.len()).find(|&i| !self.is_used(i)) else {
+            return true;
+        };
+        if self.dead.contains(&self.used) {
+            return false;
+        }
+
+        self.toggle(first);
+        let found = self.extend(&mut vec![first], self.rucksacks[first]);
+        self.toggle(first);
+        if !found {
+            self.dead.insert(self.used.clone());
+        }
+        found
+    }
+
+    /// Add rucksacks after the last one of `group`, which share `shared` so far
+    fn extend(&mut self, group: &mut Vec<usize>, shared: Items) -> bool {
+        if group.len() == self.size {
+            if shared.single().is_none() {
+                return false;
+            }
+            self.groups.push(group.clone());
+            if self.search() {
+                return true;
+            }
+            self.groups.pop();
+            return false;
+        }
+
+        for i in group[group.len() - 1] + 1..self.rucksacks.len() {
+            let shared = shared & self.rucksacks[i];
+            if self.is_used(i) || shared.is_empty() {
+                continue;
+            }
+            group.push(i);
+            self.toggle(i);
+            let found = self.extend(group, shared);
+            self.toggle(i);
+            group.pop();
+            if found {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// Split the rucksacks in groups of `size` sharing exactly one item, as lists of indices
+///
+/// The first partition found is returned, when several exist their badges may differ. `None` means
+/// that no such partition exists.
+pub fn partition(rucksacks: &[Items], size: usize) -> Option<Vec<Vec<usize>>> {
+    let mut search = Search {
+        rucksacks,
+        size,
+        used: vec![0; rucksacks.len().div_ceil(64)],
+        groups: Vec::new(),
+        dead: HashSet::new(),
+    };
+    search.search().then_some(search.groups)
+}
@@ -0,0 +1,85 @@
+use aoc_core::{Answer, Solver};
+use d03::{Items, Solution};
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part_1() {
+    assert_eq!(
+        Solution::default().part_1(EXAMPLE).unwrap(),
+        Answer::Number(157)
+    );
+}
+
+#[test]
+fn part_2() {
+    assert_eq!(
+        Solution::default().part_2(EXAMPLE).unwrap(),
+        Answer::Number(70)
+    );
+}
+
+#[test]
+fn items() {
+    let items = Items::new("vJrwpWtwJgWr") & Items::new("hcsFMMfFFhFp");
+    assert_eq!(items.single(), Some(16));
+    assert_eq!(
+        (Items::new("ab") | Items::new("bZ")).to_string(),
+        "`a`, `b`, `Z`"
+    );
+    assert_eq!(Items::ALL.len(), 52);
+}
+
+#[test]
+fn group_size() {
+    let mut solution = Solution::default();
+    solution
+        .configure(&["--group".to_string(), "6".to_string()])
+        .unwrap();
+    let err = solution.part_2(EXAMPLE).unwrap_err();
+    assert_eq!(err.to_string(), "lines 1-6: group shares no item");
+
+    solution
+        .configure(&["--group".to_string(), "4".to_string()])
+        .unwrap();
+    let err = solution.part_2(EXAMPLE).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "6 rucksacks cannot be split into groups of 4"
+    );
+}
+
+#[test]
+fn diagnose() {
+    let input = "abcd\naAaA\nxyzx\n";
+    let err = Solution::default().part_1(input).unwrap_err();
+    assert_eq!(err.to_string(), "line 1: compartments share no item");
+
+    let problems = Solution::default().diagnose(input).unwrap();
+    assert_eq!(
+        problems,
+        [
+            "line 1: compartments share no item",
+            "line 2: compartments share `a`, `A`",
+            "lines 1-3: group shares no item"
+        ]
+    );
+}
+
+#[test]
+fn unordered() {
+    let mut solution = Solution::default();
+    solution.configure(&["--unordered".to_string()]).unwrap();
+    assert_eq!(solution.part_2(EXAMPLE).unwrap(), Answer::Number(70));
+
+    // The groups are found again once the lines are mixed up
+    let lines: Vec<_> = EXAMPLE.lines().collect();
+    let shuffled = [lines[3], lines[0], lines[5], lines[1], lines[4], lines[2]].join("\n");
+    assert_eq!(solution.part_2(&shuffled).unwrap(), Answer::Number(70));
+
+    let err = solution.part_2("ab\nbc\ncd\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "the rucksacks cannot be split into groups of 3 sharing exactly one item"
+    );
+}
@@ -0,0 +1,156 @@
+//! Overlap graph between every assignment of the input
+//!
+//! With 10^5 lines the graph can have billions of edges, so it is never built: pairs are counted,
+//! and clusters and cliques found, with sweeps over the assignments sorted by start.
+
+use std::fmt::{self, Display};
+use std::ops::RangeInclusive;
+
+use crate::Elf;
+
+/// Assignments connected through overlapping ones, and the sections they span
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cluster {
+    pub sections: RangeInclusive<usize>,
+    /// Indices of the assignments
+    pub elves: Vec<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OverlapGraph {
+    pub elves: usize,
+    /// Pairs of assignments sharing at least one section
+    pub overlapping: usize,
+    /// Pairs where one assignment holds every section of the other
+    pub containing: usize,
+    /// Connected components, in order of sections
+    pub clusters: Vec<Cluster>,
+    /// Largest set of assignments which all overlap each other, as indices
+    pub clique: Vec<usize>,
+    /// Section shared by the whole clique
+    pub clique_section: Option<usize>,
+}
+
+/// Fenwick tree counting values seen so far
+struct Counts(Vec<usize>);
+
+impl Counts {
+    fn add(&mut self, i: usize) {
+        let mut i = i + 1;
+        while i < self.0.len() {
+            self.0[i] += 1;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Values added below `i`
+    fn below(&self, i: usize) -> usize {
+        let (mut i, mut n) = (i, 0);
+        while i > 0 {
+            n += self.0[i];
+            i -= i & i.wrapping_neg();
+        }
+        n
+    }
+}
+
+impl OverlapGraph {
+    pub fn new(elves: &[Elf]) -> Self {
+        let ranges: Vec<_> = elves
+            .iter()
+            .map(|e| &e.sections)
+            .enumerate()
+            .filter(|(_, r)| !r.is_empty())
+            .map(|(i, r)| (i, *r.start(), *r.end()))
+            .collect();
+        let n = ranges.len();
+
+        // Pairs that do not overlap are the ones where the second starts after the first ended
+        let mut ends: Vec<_> = ranges.iter().map(|&(_, _, end)| end).collect();
+        ends.sort_unstable();
+        let apart: usize = ranges
+            .iter()
+            .map(|&(_, start, _)| ends.partition_point(|&e| e < start))
+            .sum();
+        let overlapping = n * n.saturating_sub(1) / 2 - apart;
+
+        // An assignment contains the ones starting after it, in this order, which end before it
+        let mut by_start = ranges.clone();
+        by_start.sort_unstable_by_key(|&(i, start, end)| (start, std::cmp::Reverse(end), i));
+        ends.dedup();
+        let mut seen = Counts(vec![0; ends.len() + 1]);
+        let mut containing = 0;
+        for (k, &(_, _, end)) in by_start.iter().enumerate() {
+            let rank = ends.partition_point(|&e| e < end);
+            containing += k - seen.below(rank);
+            seen.add(rank);
+        }
+
+        let mut clusters: Vec<Cluster> = Vec::new();
+        for &(i, start, end) in &by_start {
+            match clusters.last_mut() {
+                Some(c) if start <= *c.sections.end() => {
+                    c.sections = *c.sections.start()..=end.max(*c.sections.end());
+                    c.elves.push(i);
+                }
+                _ => clusters.push(Cluster {
+                    sections: start..=end,
+                    elves: vec![i],
+                }),
+            }
+        }
+
+        // Intervals overlapping each other all share a section, the deepest one of the sweep
+        let mut events: Vec<(usize, isize)> = ranges
+            .iter()
+            .flat_map(|&(_, start, end)| [(start, 1), (end + 1, -1)])
+            .collect();
+        events.sort_unstable();
+        let (mut depth, mut best, mut clique_section) = (0, 0, None);
+        for (at, delta) in events {
+            depth += delta;
+            if depth > best {
+                (best, clique_section) = (depth, Some(at));
+            }
+        }
+        let clique = match clique_section {
+            Some(s) => ranges
+                .iter()
+                .filter(|&&(_, start, end)| start <= s && s <= end)
+                .map(|&(i, _, _)| i)
+                .collect(),
+            None => Vec::new(),
+        };
+
+        Self {
+            elves: elves.len(),
+            overlapping,
+            containing,
+            clusters,
+            clique,
+            clique_section,
+        }
+    }
+}
+
+impl Display for OverlapGraph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Assignments: {}", self.elves)?;
+        writeln!(f, "Overlapping pairs: {}", self.overlapping)?;
+        writeln!(f, "Containing pairs: {}", self.containing)?;
+        match self.clique_section {
+            Some(s) => writeln!(
+                f,
+                "Largest clique: {} assignments sharing section {s}",
+                self.clique.len()
+            )?,
+            None => writeln!(f, "Largest clique: none")?,
+        }
+        write!(f, "Clusters: {}", self.clusters.len())?;
+        for c in &self.clusters {
+            let (start, end) = (c.sections.start(), c.sections.end());
+            write!(f, "\n  {start}-{end}: {} assignments", c.elves.len())?;
+        }
+        Ok(())
+    }
+}
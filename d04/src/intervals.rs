@@ -0,0 +1,160 @@
+//! Sets of sections, stored as sorted ranges
+//!
+//! Ranges are inclusive like the assignments of the input, and kept disjoint and non-adjacent so
+//! that two equal sets always have the same ranges.
+
+use std::ops::RangeInclusive;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    ranges: Vec<(usize, usize)>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set from ranges in order, merging the ones that overlap or touch
+    fn from_sorted(sorted: impl IntoIterator<Item = (usize, usize)>) -> Self {
+        let mut ranges: Vec<(usize, usize)> = Vec::new();
+        for (start, end) in sorted {
+            match ranges.last_mut() {
+                Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+                _ => ranges.push((start, end)),
+            }
+        }
+        Self { ranges }
+    }
+
+    pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<usize>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+
+    /// Number of sections in the set
+    pub fn len(&self) -> usize {
+        self.ranges.iter().map(|(start, end)| end - start + 1).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, section: usize) -> bool {
+        let i = self.ranges.partition_point(|&(_, end)| end < section);
+        self.ranges
+            .get(i)
+            .is_some_and(|&(start, _)| start <= section)
+    }
+
+    /// Whether every section of `other` is in the set
+    pub fn is_superset(&self, other: &IntervalSet) -> bool {
+        other.difference(self).is_empty()
+    }
+
+    /// Whether the sets have a section in common
+    pub fn overlaps(&self, other: &IntervalSet) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = [&self.ranges[..], &other.ranges[..]].concat();
+        ranges.sort_unstable();
+        Self::from_sorted(ranges)
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let (mut i, mut j) = (0, 0);
+        let mut ranges = Vec::new();
+        while let (Some(&a), Some(&b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let (start, end) = (a.0.max(b.0), a.1.min(b.1));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            // The range ending first cannot overlap anything further
+            if a.1 < b.1 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+        for &(mut start, end) in &self.ranges {
+            while other.ranges.get(j).is_some_and(|&(_, e)| e < start) {
+                j += 1;
+            }
+            // Cut out every range of `other` starting inside this one
+            let mut k = j;
+            while let Some(&(s, e)) = other.ranges.get(k).filter(|&&(s, _)| s <= end) {
+                if s > start {
+                    ranges.push((start, s - 1));
+                }
+                if e >= end {
+                    start = end + 1;
+                    break;
+                }
+                start = e + 1;
+                k += 1;
+            }
+            if start <= end {
+                ranges.push((start, end));
+            }
+        }
+        Self { ranges }
+    }
+}
+
+impl From<RangeInclusive<usize>> for IntervalSet {
+    fn from(r: RangeInclusive<usize>) -> Self {
+        if r.is_empty() {
+            return Self::new();
+        }
+        Self {
+            ranges: vec![(*r.start(), *r.end())],
+        }
+    }
+}
+
+impl FromIterator<RangeInclusive<usize>> for IntervalSet {
+    fn from_iter<T: IntoIterator<Item = RangeInclusive<usize>>>(iter: T) -> Self {
+        let mut ranges: Vec<_> = iter
+            .into_iter()
+            .filter(|r| !r.is_empty())
+            .map(|r| (*r.start(), *r.end()))
+            .collect();
+        ranges.sort_unstable();
+        Self::from_sorted(ranges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operations() {
+        let a: IntervalSet = [2..=4, 6..=8, 5..=5, 12..=14].into_iter().collect();
+        assert_eq!(a.ranges().collect::<Vec<_>>(), [2..=8, 12..=14]);
+        let b: IntervalSet = [0..=2, 7..=12].into_iter().collect();
+
+        assert_eq!(a.union(&b), [0..=14].into_iter().collect());
+        assert_eq!(
+            a.intersection(&b),
+            [2..=2, 7..=8, 12..=12].into_iter().collect()
+        );
+        assert_eq!(a.difference(&b), [3..=6, 13..=14].into_iter().collect());
+        assert_eq!(b.difference(&a), [0..=1, 9..=11].into_iter().collect());
+        assert_eq!(a.len(), 10);
+        assert!(a.contains(13) && !a.contains(10));
+        assert!(a.is_superset(&IntervalSet::from(3..=7)));
+        assert!(!a.is_superset(&b) && a.overlaps(&b));
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = IntervalSet::from(3..=2);
+        assert!(empty.is_empty());
+    }
+}
@@ -0,0 +1,202 @@
+use std::fmt::{self, Display};
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use aoc_core::{parse, Answer, Solver};
+use clap::Parser;
+use eyre::Result;
+
+use intervals::IntervalSet;
+
+pub mod graph;
+pub mod intervals;
+
+struct Pair {
+    a: RangeInclusive<usize>,
+    b: RangeInclusive<usize>,
+}
+
+impl FromStr for Pair {
+    type Err = eyre::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let range = |r: &str| -> Result<RangeInclusive<usize>> {
+            let (start, end) = parse::split_once(s, r, "-")?;
+            Ok(parse::number(s, start)?..=parse::number(s, end)?)
+        };
+        let (a, b) = parse::split_once(s, s, ",")?;
+
+        Ok(Self {
+            a: range(a)?,
+            b: range(b)?,
+        })
+    }
+}
+
+fn check(Pair { a, b }: &Pair) -> bool {
+    let (a, b) = (IntervalSet::from(a.clone()), IntervalSet::from(b.clone()));
+    a.is_superset(&b) || b.is_superset(&a)
+}
+
+fn check_2(Pair { a, b }: &Pair) -> bool {
+    IntervalSet::from(a.clone()).overlaps(&IntervalSet::from(b.clone()))
+}
+
+/// Assignment of one elf, along with the line it comes from
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Elf {
+    pub line: usize,
+    pub sections: RangeInclusive<usize>,
+}
+
+impl Display for Elf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (start, end) = (self.sections.start(), self.sections.end());
+        write!(f, "{start}-{end} on line {}", self.line)
+    }
+}
+
+/// Every assignment of the input, both elves of a line one after the other
+pub fn elves(input: &str) -> Result<Vec<Elf>> {
+    let mut elves = Vec::new();
+    for (i, pair) in parse::lines(input, str::parse::<Pair>).enumerate() {
+        let Pair { a, b } = pair?;
+        elves.push(Elf {
+            line: i + 1,
+            sections: a,
+        });
+        elves.push(Elf {
+            line: i + 1,
+            sections: b,
+        });
+    }
+    Ok(elves)
+}
+
+/// Sections covered by the elves, found sweeping over the starts and ends of their assignments
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Coverage {
+    /// Sections assigned to at least one elf
+    pub covered: usize,
+    /// Sections assigned to a single elf
+    pub once: usize,
+    /// The two elves sharing the most sections, and how many they share
+    pub largest_overlap: Option<(Elf, Elf, usize)>,
+}
+
+impl Coverage {
+    pub fn new(elves: &[Elf]) -> Self {
+        let mut events: Vec<(usize, isize)> = elves
+            .iter()
+            .filter(|e| !e.sections.is_empty())
+            .flat_map(|e| [(*e.sections.start(), 1), (e.sections.end() + 1, -1)])
+            .collect();
+        events.sort_unstable();
+
+        let (mut covered, mut once, mut depth, mut last) = (0, 0, 0, 0);
+        for (at, delta) in events {
+            if depth >= 1 {
+                covered += at - last;
+            }
+            if depth == 1 {
+                once += at - last;
+            }
+            depth += delta;
+            last = at;
+        }
+
+        Self {
+            covered,
+            once,
+            largest_overlap: largest_overlap(elves),
+        }
+    }
+}
+
+/// Pair of elves with the most sections in common
+///
+/// In order of start, an elf overlaps the most with the elf reaching the furthest among the ones
+/// starting before it, so a single sweep keeping that elf is enough.
+fn largest_overlap(elves: &[Elf]) -> Option<(Elf, Elf, usize)> {
+    let mut order: Vec<_> = (0..elves.len())
+        .filter(|&i| !elves[i].sections.is_empty())
+        .collect();
+    order.sort_by_key(|&i| elves[i].sections.start());
+
+    let mut best: Option<(usize, usize, usize)> = None;
+    let mut furthest: Option<usize> = None;
+    for i in order {
+        let sections = &elves[i].sections;
+        if let Some(j) = furthest {
+            let end = sections.end().min(elves[j].sections.end());
+            let overlap = (end + 1).saturating_sub(*sections.start());
+            if overlap > 0 && best.is_none_or(|(_, _, b)| overlap > b) {
+                best = Some((j, i, overlap));
+            }
+        }
+        if furthest.is_none_or(|j| sections.end() > elves[j].sections.end()) {
+            furthest = Some(i);
+        }
+    }
+
+    best.map(|(i, j, overlap)| (elves[i].clone(), elves[j].clone(), overlap))
+}
+
+impl Display for Coverage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Sections covered: {}", self.covered)?;
+        writeln!(f, "Sections covered once: {}", self.once)?;
+        match &self.largest_overlap {
+            Some((a, b, overlap)) => write!(f, "Largest overlap: {overlap} sections, {a} and {b}"),
+            None => write!(f, "Largest overlap: none"),
+        }
+    }
+}
+
+/// Puzzle parameters, the defaults solve the real input
+#[derive(Clone, Debug, Parser)]
+pub struct Params {
+    /// Print the sections covered by the elves and their largest overlap instead of the answers
+    #[arg(long)]
+    pub coverage: bool,
+    /// Print the overlaps between every assignment of the input instead of the answers
+    #[arg(long)]
+    pub graph: bool,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self::parse_from([""])
+    }
+}
+
+#[derive(Default)]
+pub struct Solution {
+    pub params: Params,
+}
+
+impl Solver for Solution {
+    fn parse(&self, input: &str) -> Result<()> {
+        parse::lines(input, str::parse::<Pair>).collect::<Result<Vec<_>>>()?;
+
+        Ok(())
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        let pairs: Vec<Pair> = parse::lines(input, str::parse).collect::<Result<_>>()?;
+        let r = pairs.iter().filter(|p| check(p)).count();
+
+        Ok(r.into())
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        let pairs: Vec<Pair> = parse::lines(input, str::parse).collect::<Result<_>>()?;
+        let r = pairs.iter().filter(|p| check_2(p)).count();
+
+        Ok(r.into())
+    }
+
+    fn configure(&mut self, args: &[String]) -> Result<()> {
+        aoc_core::configure(&mut self.params, args)
+    }
+}
@@ -0,0 +1,36 @@
+use aoc_core::{Answer, Solver};
+use d04::intervals::IntervalSet;
+use d04::{elves, Coverage, Solution};
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part_1() {
+    assert_eq!(
+        Solution::default().part_1(EXAMPLE).unwrap(),
+        Answer::Number(2)
+    );
+}
+
+#[test]
+fn part_2() {
+    assert_eq!(
+        Solution::default().part_2(EXAMPLE).unwrap(),
+        Answer::Number(4)
+    );
+}
+
+#[test]
+fn coverage() {
+    let elves = elves(EXAMPLE).unwrap();
+    let coverage = Coverage::new(&elves);
+    assert_eq!((coverage.covered, coverage.once), (8, 1));
+    let union: IntervalSet = elves.iter().map(|e| e.sections.clone()).collect();
+    assert_eq!(union.len(), coverage.covered);
+
+    let (a, b, overlap) = coverage.largest_overlap.unwrap();
+    assert_eq!(
+        (a.to_string(), b.to_string(), overlap),
+        ("2-8 on line 4".into(), "2-6 on line 6".into(), 5)
+    );
+}
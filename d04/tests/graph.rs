@@ -0,0 +1,81 @@
+use d04::graph::OverlapGraph;
+use d04::{elves, Elf};
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+fn overlap(a: &Elf, b: &Elf) -> bool {
+    a.sections.start() <= b.sections.end() && b.sections.start() <= a.sections.end()
+}
+
+fn contains(a: &Elf, b: &Elf) -> bool {
+    a.sections.start() <= b.sections.start() && b.sections.end() <= a.sections.end()
+}
+
+/// Pairs counted one by one, to check the sweeps against
+fn pairs(elves: &[Elf]) -> (usize, usize) {
+    let (mut overlapping, mut containing) = (0, 0);
+    for (i, a) in elves.iter().enumerate() {
+        for b in &elves[i + 1..] {
+            overlapping += usize::from(overlap(a, b));
+            containing += usize::from(contains(a, b) || contains(b, a));
+        }
+    }
+    (overlapping, containing)
+}
+
+#[test]
+fn example() {
+    let elves = elves(EXAMPLE).unwrap();
+    let graph = OverlapGraph::new(&elves);
+    assert_eq!((graph.overlapping, graph.containing), pairs(&elves));
+    assert_eq!(graph.clusters.len(), 1);
+    assert_eq!(graph.clusters[0].sections, 2..=9);
+    assert_eq!(graph.clique.len(), 8);
+    assert!(graph
+        .clique
+        .iter()
+        .all(|&i| elves[i].sections.contains(&graph.clique_section.unwrap())));
+}
+
+#[test]
+fn generated() {
+    // Small linear congruential generator, for the test to be reproducible
+    let mut seed = 7u64;
+    let mut random = |n: u64| {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33) % n
+    };
+    let mut range = || {
+        let start = random(500);
+        format!("{start}-{}", start + random(8))
+    };
+    let input: String = (0..300)
+        .map(|_| format!("{},{}\n", range(), range()))
+        .collect();
+
+    let elves = elves(&input).unwrap();
+    let graph = OverlapGraph::new(&elves);
+    assert_eq!((graph.overlapping, graph.containing), pairs(&elves));
+
+    // Clusters are the connected components
+    for c in &graph.clusters {
+        for &i in &c.elves {
+            assert!(graph
+                .clusters
+                .iter()
+                .filter(|o| o != &c)
+                .all(|o| o.elves.iter().all(|&j| !overlap(&elves[i], &elves[j]))));
+        }
+    }
+    let largest = (0..elves.len())
+        .map(|i| {
+            elves
+                .iter()
+                .filter(|e| e.sections.contains(elves[i].sections.start()))
+                .count()
+        })
+        .max();
+    assert_eq!(Some(graph.clique.len()), largest);
+}
@@ -0,0 +1,98 @@
+//! Crane models, picked by name from the command line
+//!
+//! | Name            | Model                                                           |
+//! |-----------------|-----------------------------------------------------------------|
+//! | `9000`          | CrateMover 9000, one crate at a time                            |
+//! | `9001`          | CrateMover 9001, every crate at once                            |
+//! | `capacity:K`    | at most `K` crates at once                                      |
+//! | `alternating:K` | at most `K` crates at once, turning every other load upside down |
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+/// Moves the top crates of a stack onto another one
+pub trait Crane: Debug + Send + Sync {
+    /// Move the top `n` crates of `from` onto `to`, `from` holding at least `n` crates
+    fn apply(&self, n: usize, from: &mut Vec<char>, to: &mut Vec<char>);
+}
+
+/// Move the top `n` crates of `from` in loads of at most `capacity`
+///
+/// Each load keeps its order, unless `flip` says to turn the load with that index upside down.
+fn move_loads(
+    n: usize,
+    capacity: usize,
+    from: &mut Vec<char>,
+    to: &mut Vec<char>,
+    flip: impl Fn(usize) -> bool,
+) {
+    let mut left = n;
+    for load in 0.. {
+        if left == 0 {
+            break;
+        }
+        let size = left.min(capacity);
+        let start = from.len() - size;
+        if flip(load) {
+            to.extend(from.drain(start..).rev());
+        } else {
+            to.extend(from.drain(start..));
+        }
+        left -= size;
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn apply(&self, n: usize, from: &mut Vec<char>, to: &mut Vec<char>) {
+        move_loads(n, 1, from, to, |_| false);
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn apply(&self, n: usize, from: &mut Vec<char>, to: &mut Vec<char>) {
+        let start = from.len() - n;
+        to.extend(from.drain(start..));
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Capacity(pub usize);
+
+impl Crane for Capacity {
+    fn apply(&self, n: usize, from: &mut Vec<char>, to: &mut Vec<char>) {
+        move_loads(n, self.0, from, to, |_| false);
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Alternating(pub usize);
+
+impl Crane for Alternating {
+    fn apply(&self, n: usize, from: &mut Vec<char>, to: &mut Vec<char>) {
+        move_loads(n, self.0, from, to, |load| load % 2 == 1);
+    }
+}
+
+/// Crane model from its name, see the module documentation
+pub fn by_name(name: &str) -> Result<Arc<dyn Crane>, String> {
+    let capacity = |k: &str| match k.parse() {
+        Ok(0) | Err(_) => Err(format!("expected a capacity of at least 1, found `{k}`")),
+        Ok(k) => Ok(k),
+    };
+
+    match name.split_once(':') {
+        None if name == "9000" => Ok(Arc::new(CrateMover9000)),
+        None if name == "9001" => Ok(Arc::new(CrateMover9001)),
+        Some(("capacity", k)) => Ok(Arc::new(Capacity(capacity(k)?))),
+        Some(("alternating", k)) => Ok(Arc::new(Alternating(capacity(k)?))),
+        _ => Err(format!(
+            "unknown crane `{name}`, expected `9000`, `9001`, `capacity:K` or `alternating:K`"
+        )),
+    }
+}
@@ -0,0 +1,273 @@
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use aoc_core::parse::{self, ParseError};
+use aoc_core::{debug, Answer, Solver};
+use aoc_grid::Grid;
+use clap::{Parser, ValueEnum};
+use eyre::{bail, Result, WrapErr};
+
+use crane::Crane;
+use reverse::Reconstruction;
+
+pub mod crane;
+pub mod reverse;
+
+struct Command {
+    n: usize,
+    from: usize,
+    to: usize,
+}
+
+impl Command {
+    pub fn parse_str(s: &str) -> Result<Self> {
+        let stack = |t: &str| match parse::number::<usize>(s, t)? {
+            0 => Err(ParseError::new(s, t, "a stack number starting from 1")),
+            i => Ok(i - 1),
+        };
+
+        let rest = parse::tag(s, s, "move ")?;
+        let (n, rest) = parse::split_once(s, rest, " from ")?;
+        let (from, to) = parse::split_once(s, rest, " to ")?;
+        Ok(Self {
+            n: parse::number(s, n)?,
+            from: stack(from)?,
+            to: stack(to)?,
+        })
+    }
+}
+
+impl Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.n,
+            self.from + 1,
+            self.to + 1
+        )
+    }
+}
+
+/// Stacks of a drawing, bottom crate first
+pub fn parse_setup(setup: &str) -> Result<Vec<Vec<char>>> {
+    let s = Grid::from_text_padded(setup, ' ')?;
+
+    Ok(s.columns()
+        .skip(1)
+        .step_by(4)
+        .map(|r| {
+            r.into_iter()
+                .rev()
+                .skip(1)
+                .take_while(|&&c| c != ' ')
+                .cloned()
+                .collect()
+        })
+        .collect())
+}
+
+/// Draw the stacks like the puzzle input, numbers at the bottom included
+pub fn render(stacks: &[Vec<char>]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut lines: Vec<String> = (0..height)
+        .rev()
+        .map(|level| {
+            let crates: Vec<_> = stacks
+                .iter()
+                .map(|s| s.get(level).map_or("   ".to_string(), |c| format!("[{c}]")))
+                .collect();
+            crates.join(" ")
+        })
+        .collect();
+    let numbers: Vec<_> = (1..=stacks.len()).map(|i| format!("{i:^3}")).collect();
+    lines.push(numbers.join(" "));
+    lines.join("\n")
+}
+
+/// Split the input in the starting stacks and the list of moves
+fn parse_input(input: &str) -> Result<(Vec<Vec<char>>, Vec<Command>)> {
+    let (setup, commands) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::new(
+            input,
+            &input[input.len()..],
+            "an empty line between the drawing and the moves",
+        )
+    })?;
+
+    let commands = commands
+        .lines()
+        .map(|l| Command::parse_str(l).map_err(|e| parse::rebase(input, l, e)))
+        .collect::<Result<_>>()?;
+
+    Ok((parse_setup(setup)?, commands))
+}
+
+/// What to do with a move which cannot be made
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum OnError {
+    /// Stop with an error
+    Abort,
+    /// Move as many crates as there are, skip moves between missing stacks
+    Clamp,
+}
+
+/// Crates of a stack, bottom first
+fn crates(stack: &[char]) -> String {
+    let crates: Vec<_> = stack.iter().map(|c| format!("[{c}]")).collect();
+    crates.join(" ")
+}
+
+/// Why a move cannot be made, if it cannot
+fn invalid(&Command { n, from, to }: &Command, state: &[Vec<char>]) -> Option<String> {
+    if let Some(s) = [from, to].into_iter().find(|&s| s >= state.len()) {
+        return Some(format!("there is no stack {}, only {}", s + 1, state.len()));
+    }
+    let stack = &state[from];
+    (n > stack.len()).then(|| match stack.len() {
+        0 => format!("stack {} is empty", from + 1),
+        len => format!(
+            "stack {} only holds {len} crates: {}",
+            from + 1,
+            crates(stack)
+        ),
+    })
+}
+
+/// Run the moves with a crane, calling `step` after each one, and read the crates on top
+///
+/// Empty stacks are left out of the result.
+fn top_crates(
+    input: &str,
+    crane: &dyn Crane,
+    on_error: OnError,
+    mut step: impl FnMut(&Command, &[Vec<char>]),
+) -> Result<String> {
+    let (mut state, commands) = parse_input(input)?;
+
+    for (i, command) in commands.iter().enumerate() {
+        if let Some(problem) = invalid(command, &state) {
+            let problem = format!("command {} `{command}`: {problem}", i + 1);
+            match on_error {
+                OnError::Abort => bail!(problem),
+                OnError::Clamp => debug!("{problem}, clamping"),
+            }
+        }
+
+        let &Command { n, from, to } = command;
+        // Crates put back on their own stack end up where they were
+        if from != to && from < state.len() && to < state.len() {
+            let mut stack = std::mem::take(&mut state[from]);
+            crane.apply(n.min(stack.len()), &mut stack, &mut state[to]);
+            state[from] = stack;
+        }
+        step(command, &state);
+    }
+
+    Ok(state.iter().filter_map(|l| l.last()).collect())
+}
+
+/// Puzzle parameters, the defaults solve the real input
+#[derive(Clone, Debug, Parser)]
+pub struct Params {
+    /// Crane of part 1: `9000`, `9001`, `capacity:K` or `alternating:K`
+    #[arg(long, default_value = "9000", value_parser = crane::by_name)]
+    pub crane_1: Arc<dyn Crane>,
+    /// Crane of part 2
+    #[arg(long, default_value = "9001", value_parser = crane::by_name)]
+    pub crane_2: Arc<dyn Crane>,
+    /// Print the stacks after every move
+    #[arg(long)]
+    pub trace: bool,
+    /// What to do with moves which cannot be made
+    #[arg(long, value_enum, default_value_t = OnError::Abort)]
+    pub on_error: OnError,
+    /// Print the initial stacks leading to these crates on top instead of the answers
+    #[arg(long, conflicts_with = "reverse_drawing")]
+    pub reverse_tops: Option<String>,
+    /// Print the initial stacks leading to the stacks drawn in this file instead of the answers
+    #[arg(long)]
+    pub reverse_drawing: Option<PathBuf>,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self::parse_from([""])
+    }
+}
+
+#[derive(Default)]
+pub struct Solution {
+    pub params: Params,
+}
+
+impl Solution {
+    /// Initial stacks of both parts leading to `--reverse-tops` or `--reverse-drawing`
+    pub fn reverse(&self, input: &str) -> Result<Vec<Reconstruction>> {
+        let (initial, commands) = parse_input(input)?;
+        let target = match &self.params.reverse_drawing {
+            Some(path) => {
+                let drawing = fs::read_to_string(path)
+                    .wrap_err_with(|| format!("cannot read {}", path.display()))?;
+                Some(parse_setup(&drawing)?)
+            }
+            None => None,
+        };
+
+        [&self.params.crane_1, &self.params.crane_2]
+            .into_iter()
+            .map(|crane| match (&target, &self.params.reverse_tops) {
+                (Some(target), _) => reverse::from_stacks(target, &commands, &**crane),
+                (None, Some(tops)) => reverse::from_tops(&initial, tops, &commands, &**crane),
+                (None, None) => bail!("expected --reverse-tops or --reverse-drawing"),
+            })
+            .collect()
+    }
+
+    /// Print the stacks of both parts after every move
+    pub fn trace(&self, input: &str) -> Result<()> {
+        let (state, _) = parse_input(input)?;
+        for (part, crane) in [(1, &self.params.crane_1), (2, &self.params.crane_2)] {
+            println!("Part {part}, {crane:?}\n\n{}\n", render(&state));
+            top_crates(input, &**crane, self.params.on_error, |command, state| {
+                println!("{command}\n\n{}\n", render(state));
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Solver for Solution {
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_input(input)?;
+
+        Ok(())
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        Ok(top_crates(
+            input,
+            &*self.params.crane_1,
+            self.params.on_error,
+            |_, _| (),
+        )?
+        .into())
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        Ok(top_crates(
+            input,
+            &*self.params.crane_2,
+            self.params.on_error,
+            |_, _| (),
+        )?
+        .into())
+    }
+
+    fn configure(&mut self, args: &[String]) -> Result<()> {
+        aoc_core::configure(&mut self.params, args)
+    }
+}
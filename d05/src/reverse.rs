@@ -0,0 +1,140 @@
+//! Initial stacks reconstructed from the final ones, running the moves backwards
+//!
+//! A crane always lands the crates it lifts in the same order for a given number of crates, so
+//! undoing a move puts the crates back in the order found by running the crane on labelled
+//! crates. When only the top crates are known, the other ones stay unknown (`None`) all along.
+
+use std::fmt::{self, Display};
+
+use eyre::{bail, ensure, Result};
+
+use crate::crane::Crane;
+use crate::{render, Command};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reconstruction {
+    /// Stacks, bottom crate first, `None` for the crates that cannot be determined
+    pub stacks: Vec<Vec<Option<char>>>,
+}
+
+impl Reconstruction {
+    /// Crates left unknown, the reconstruction is ambiguous when there are any
+    pub fn unknown(&self) -> usize {
+        self.stacks.iter().flatten().filter(|c| c.is_none()).count()
+    }
+}
+
+impl Display for Reconstruction {
+    /// Drawing of the stacks, unknown crates being drawn as `[?]`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let stacks: Vec<Vec<char>> = self
+            .stacks
+            .iter()
+            .map(|s| s.iter().map(|c| c.unwrap_or('?')).collect())
+            .collect();
+        write!(f, "{}", render(&stacks))
+    }
+}
+
+/// Where the crane puts `n` lifted crates: the `j`-th crate landed, from the bottom, is the
+/// `order[j]`-th one lifted
+fn order(crane: &dyn Crane, n: usize) -> Vec<usize> {
+    // Private use area characters as labels
+    let label = |i: usize| char::from_u32(0xF0000 + i as u32).expect("too many crates to label");
+    let mut from: Vec<_> = (0..n).map(label).collect();
+    let mut to = Vec::new();
+    crane.apply(n, &mut from, &mut to);
+    to.into_iter()
+        .map(|c| (c as u32 - 0xF0000) as usize)
+        .collect()
+}
+
+/// Undo the moves from the final stacks
+fn unwind(
+    mut stacks: Vec<Vec<Option<char>>>,
+    commands: &[Command],
+    crane: &dyn Crane,
+) -> Result<Reconstruction> {
+    for (i, command) in commands.iter().enumerate().rev() {
+        let &Command { n, from, to } = command;
+        if let Some(s) = [from, to].into_iter().find(|&s| s >= stacks.len()) {
+            bail!("command {} `{command}`: there is no stack {}", i + 1, s + 1);
+        }
+        if from == to {
+            continue;
+        }
+        let held = stacks[to].len();
+        ensure!(
+            held >= n,
+            "impossible: command {} `{command}` leaves {n} crates on stack {}, which only holds {held}",
+            i + 1,
+            to + 1
+        );
+
+        let landed = stacks[to].split_off(held - n);
+        let mut lifted = vec![None; n];
+        for (c, j) in landed.into_iter().zip(order(crane, n)) {
+            lifted[j] = c;
+        }
+        stacks[from].extend(lifted);
+    }
+
+    Ok(Reconstruction { stacks })
+}
+
+/// Initial stacks leading to the `target` stacks
+pub(crate) fn from_stacks(
+    target: &[Vec<char>],
+    commands: &[Command],
+    crane: &dyn Crane,
+) -> Result<Reconstruction> {
+    let stacks = target
+        .iter()
+        .map(|s| s.iter().copied().map(Some).collect())
+        .collect();
+    unwind(stacks, commands, crane)
+}
+
+/// Initial stacks, as high as the `initial` ones, leading to the `tops` crates on top
+///
+/// `tops` has one crate per stack that is not empty at the end, like the answers.
+pub(crate) fn from_tops(
+    initial: &[Vec<char>],
+    tops: &str,
+    commands: &[Command],
+    crane: &dyn Crane,
+) -> Result<Reconstruction> {
+    // Heights only depend on the moves
+    let mut heights: Vec<_> = initial.iter().map(Vec::len).collect();
+    for (i, command) in commands.iter().enumerate() {
+        let &Command { n, from, to } = command;
+        ensure!(
+            from < heights.len() && to < heights.len() && heights[from] >= n,
+            "command {} `{command}` cannot be made with the stacks of the drawing",
+            i + 1
+        );
+        heights[from] -= n;
+        heights[to] += n;
+    }
+
+    let filled = heights.iter().filter(|&&h| h > 0).count();
+    let tops: Vec<char> = tops.chars().collect();
+    ensure!(
+        tops.len() == filled,
+        "impossible: {filled} stacks hold crates at the end, but {} top crates were given",
+        tops.len()
+    );
+
+    let mut tops = tops.into_iter();
+    let stacks = heights
+        .iter()
+        .map(|&h| {
+            let mut stack = vec![None; h];
+            if let Some(top) = stack.last_mut() {
+                *top = tops.next();
+            }
+            stack
+        })
+        .collect();
+    unwind(stacks, commands, crane)
+}
@@ -0,0 +1,143 @@
+use aoc_core::{Answer, Solver};
+use d05::crane::{Alternating, Capacity, Crane, CrateMover9000, CrateMover9001};
+use d05::Solution;
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part_1() {
+    assert_eq!(
+        Solution::default().part_1(EXAMPLE).unwrap(),
+        Answer::from("CMZ")
+    );
+}
+
+#[test]
+fn part_2() {
+    assert_eq!(
+        Solution::default().part_2(EXAMPLE).unwrap(),
+        Answer::from("MCD")
+    );
+}
+
+#[test]
+fn cranes() {
+    let solution = |crane: &str| {
+        let mut solution = Solution::default();
+        solution
+            .configure(&["--crane-1".to_string(), crane.to_string()])
+            .unwrap();
+        solution.part_1(EXAMPLE).unwrap()
+    };
+    assert_eq!(solution("9001"), Answer::from("MCD"));
+    assert_eq!(solution("capacity:1"), Answer::from("CMZ"));
+    // The move of 3 crates takes two loads
+    assert_eq!(solution("capacity:2"), Answer::from("MCZ"));
+    assert_eq!(solution("alternating:1"), Answer::from("CMZ"));
+
+    let loads = |crane: &dyn Crane| {
+        let (mut from, mut to) = ("abcde".chars().collect(), Vec::new());
+        crane.apply(5, &mut from, &mut to);
+        to.into_iter().collect::<String>()
+    };
+    assert_eq!(loads(&CrateMover9000), "edcba");
+    assert_eq!(loads(&CrateMover9001), "abcde");
+    assert_eq!(loads(&Capacity(2)), "debca");
+    assert_eq!(loads(&Alternating(2)), "decba");
+
+    let mut solution = Solution::default();
+    let err = solution
+        .configure(&["--crane-2".to_string(), "9002".to_string()])
+        .unwrap_err();
+    assert!(err.to_string().contains("unknown crane `9002`"), "{err}");
+}
+
+#[test]
+fn render() {
+    let (setup, _) = EXAMPLE.split_once("\n\n").unwrap();
+    let stacks = d05::parse_setup(setup).unwrap();
+    assert_eq!(stacks, [vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]);
+    assert_eq!(d05::render(&stacks), setup);
+}
+
+#[test]
+fn invalid_moves() {
+    let input = EXAMPLE.replace("move 3 from 1 to 3", "move 4 from 1 to 3");
+    let err = Solution::default().part_1(&input).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "command 2 `move 4 from 1 to 3`: stack 1 only holds 3 crates: [Z] [N] [D]"
+    );
+
+    let input = EXAMPLE.replace("move 1 from 1 to 2", "move 1 from 1 to 4");
+    let err = Solution::default().part_1(&input).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "command 4 `move 1 from 1 to 4`: there is no stack 4, only 3"
+    );
+
+    // Clamping moves the 3 crates there are, and skips the move to a missing stack
+    let mut solution = Solution::default();
+    solution
+        .configure(&["--on-error=clamp".to_string()])
+        .unwrap();
+    let input = EXAMPLE.replace("move 3 from 1 to 3", "move 4 from 1 to 3");
+    assert_eq!(solution.part_1(&input).unwrap(), Answer::from("CMZ"));
+    assert_eq!(solution.part_2(&input).unwrap(), Answer::from("MCD"));
+    // Stack 2 ends up empty and has no crate on top
+    let input = EXAMPLE.replace("move 1 from 1 to 2", "move 1 from 1 to 4");
+    assert_eq!(solution.part_1(&input).unwrap(), Answer::from("MZ"));
+}
+
+#[test]
+fn reverse() {
+    let (setup, _) = EXAMPLE.split_once("\n\n").unwrap();
+    let initial: Vec<Vec<_>> = d05::parse_setup(setup)
+        .unwrap()
+        .into_iter()
+        .map(|s| s.into_iter().map(Some).collect())
+        .collect();
+
+    // Final stacks of both cranes, as drawn by `--trace`
+    let dir = std::env::temp_dir().join(format!("d05-reverse-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("final.txt");
+    std::fs::write(
+        &path,
+        "        [Z]\n        [N]\n        [D]\n[C] [M] [P]\n 1   2   3 ",
+    )
+    .unwrap();
+    let mut solution = Solution::default();
+    solution
+        .configure(&["--reverse-drawing".to_string(), path.display().to_string()])
+        .unwrap();
+    let [part_1, part_2] = &solution.reverse(EXAMPLE).unwrap()[..] else {
+        panic!("expected one reconstruction per part");
+    };
+    assert_eq!(part_1.stacks, initial);
+    assert_eq!(part_1.unknown(), 0);
+    // The 9001 never reached these stacks, undoing its moves gives other initial stacks
+    assert_ne!(part_2.stacks, initial);
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let mut solution = Solution::default();
+    solution
+        .configure(&["--reverse-tops=CMZ".to_string()])
+        .unwrap();
+    let reconstructed = &solution.reverse(EXAMPLE).unwrap()[0];
+    assert_eq!(reconstructed.unknown(), 3);
+    // The known crates are where the example drawing has them
+    assert_eq!(
+        reconstructed.to_string(),
+        "    [?]    \n[?] [C]    \n[Z] [M] [?]\n 1   2   3 "
+    );
+
+    solution
+        .configure(&["--reverse-tops=CM".to_string()])
+        .unwrap();
+    let err = solution.reverse(EXAMPLE).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "impossible: 3 stacks hold crates at the end, but 2 top crates were given"
+    );
+}
@@ -0,0 +1,276 @@
+use std::collections::VecDeque;
+use std::io::BufRead;
+
+use aoc_core::{Answer, Solver};
+use clap::Parser;
+use eyre::{ensure, eyre, Result};
+
+/// Finds the windows of `size` different bytes in a datastream, in constant time per byte
+///
+/// The counts of the bytes in the window are kept, along with how many of them are not zero.
+pub struct Detector {
+    size: usize,
+    window: VecDeque<u8>,
+    counts: [usize; 256],
+    distinct: usize,
+    read: usize,
+}
+
+impl Detector {
+    pub fn new(size: usize) -> Self {
+        Self {
+            size,
+            window: VecDeque::with_capacity(size + 1),
+            counts: [0; 256],
+            distinct: 0,
+            read: 0,
+        }
+    }
+
+    /// Add the next byte, the number of bytes read so far if they end with a marker
+    pub fn push(&mut self, b: u8) -> Option<usize> {
+        self.read += 1;
+        self.window.push_back(b);
+        self.counts[b as usize] += 1;
+        if self.counts[b as usize] == 1 {
+            self.distinct += 1;
+        }
+
+        if self.window.len() > self.size {
+            let old = self.window.pop_front().unwrap() as usize;
+            self.counts[old] -= 1;
+            if self.counts[old] == 0 {
+                self.distinct -= 1;
+            }
+        }
+
+        (self.distinct == self.size).then_some(self.read)
+    }
+
+    /// Start over with a new datastream
+    pub fn reset(&mut self) {
+        *self = Self::new(self.size);
+    }
+}
+
+/// Marker found in a datastream
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Marker {
+    /// Line of the datastream, from 1
+    pub line: usize,
+    /// Characters read up to the end of the marker
+    pub position: usize,
+    pub size: usize,
+    /// Which of the sizes searched for found the marker, so that equal sizes stay apart
+    pub index: usize,
+}
+
+/// Look for markers of every size at once, each line being its own datastream
+///
+/// The input is read a buffer at a time, so it never has to fit in memory.
+pub fn markers(
+    mut reader: impl BufRead,
+    sizes: &[usize],
+    mut found: impl FnMut(Marker),
+) -> Result<()> {
+    ensure!(
+        sizes.iter().all(|&s| s > 0),
+        "markers must be at least one character long"
+    );
+    let mut detectors: Vec<_> = sizes.iter().map(|&s| Detector::new(s)).collect();
+    let mut line = 1;
+
+    loop {
+        let buf = reader.fill_buf()?;
+        if buf.is_empty() {
+            return Ok(());
+        }
+        for &b in buf {
+            match b {
+                b'\n' => {
+                    detectors.iter_mut().for_each(Detector::reset);
+                    line += 1;
+                }
+                b'\r' => (),
+                _ => {
+                    for (index, d) in detectors.iter_mut().enumerate() {
+                        if let Some(position) = d.push(b) {
+                            found(Marker {
+                                line,
+                                position,
+                                size: d.size,
+                                index,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        let len = buf.len();
+        reader.consume(len);
+    }
+}
+
+/// Part of a datastream following a marker, up to the next marker of the same size
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Frame {
+    /// Characters before the frame
+    pub offset: usize,
+    pub len: usize,
+}
+
+/// Split a datastream in frames, each marker starting the search for the next one over
+pub fn frames(stream: &[u8], size: usize) -> Vec<Frame> {
+    let mut detector = Detector::new(size);
+    let mut frames: Vec<Frame> = Vec::new();
+    for (i, &b) in stream.iter().enumerate() {
+        if detector.push(b).is_some() {
+            if let Some(last) = frames.last_mut() {
+                last.len = i + 1 - size - last.offset;
+            }
+            frames.push(Frame {
+                offset: i + 1,
+                len: 0,
+            });
+            detector.reset();
+        }
+    }
+    if let Some(last) = frames.last_mut() {
+        last.len = stream.len() - last.offset;
+    }
+    frames
+}
+
+/// Frames of one line of the input
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Decoded {
+    pub line: usize,
+    pub packets: Vec<Frame>,
+    pub messages: Vec<Frame>,
+}
+
+/// Position of the first marker of `size` in the first datastream, the parts only look at it
+fn first_marker(input: &str, size: usize) -> Result<Option<usize>> {
+    let line = input.lines().next().unwrap_or_default();
+    let mut first = None;
+    markers(line.as_bytes(), &[size], |m| {
+        first.get_or_insert(m.position);
+    })?;
+    Ok(first)
+}
+
+/// Puzzle parameters, the defaults solve the real input
+#[derive(Clone, Debug, Parser)]
+pub struct Params {
+    /// Length of the start-of-packet markers
+    #[arg(long, default_value_t = 4)]
+    pub packet: usize,
+    /// Length of the start-of-message markers
+    #[arg(long, default_value_t = 14)]
+    pub message: usize,
+    /// Print every marker of every line instead of the answers
+    #[arg(long)]
+    pub all: bool,
+    /// Print the packet and message frames of every line instead of the answers
+    #[arg(long)]
+    pub frames: bool,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self::parse_from([""])
+    }
+}
+
+#[derive(Default)]
+pub struct Solution {
+    pub params: Params,
+}
+
+impl Solution {
+    /// Print every marker while reading the datastreams
+    pub fn print_markers(&self, reader: impl BufRead) -> Result<()> {
+        let Params {
+            packet, message, ..
+        } = self.params;
+        markers(reader, &[packet, message], |m| {
+            let kind = ["packet", "message"][m.index];
+            println!("line {}: {kind} at {}", m.line, m.position);
+        })
+    }
+
+    /// Packet and message frames of every line
+    pub fn decode(&self, input: &str) -> Vec<Decoded> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, l)| Decoded {
+                line: i + 1,
+                packets: frames(l.as_bytes(), self.params.packet),
+                messages: frames(l.as_bytes(), self.params.message),
+            })
+            .collect()
+    }
+
+    /// Table of the frames found on each line, then of every frame
+    pub fn frame_table(&self, input: &str) -> String {
+        let decoded = self.decode(input);
+        let first = |frames: &[Frame]| {
+            frames
+                .first()
+                .map_or("-".to_string(), |f| f.offset.to_string())
+        };
+
+        let mut table = format!(
+            "{:>6}{:>9}{:>9}{:>9}{:>9}\n",
+            "Line", "Packet", "Message", "Packets", "Messages"
+        );
+        for d in &decoded {
+            table += &format!(
+                "{:>6}{:>9}{:>9}{:>9}{:>9}\n",
+                d.line,
+                first(&d.packets),
+                first(&d.messages),
+                d.packets.len(),
+                d.messages.len()
+            );
+        }
+
+        table += &format!(
+            "\n{:>6}{:>9}{:>9}{:>9}\n",
+            "Line", "Frame", "Offset", "Length"
+        );
+        for d in &decoded {
+            let frames = d.packets.iter().map(|f| ("packet", f));
+            for (kind, f) in frames.chain(d.messages.iter().map(|f| ("message", f))) {
+                table += &format!("{:>6}{kind:>9}{:>9}{:>9}\n", d.line, f.offset, f.len);
+            }
+        }
+        table
+    }
+}
+
+impl Solver for Solution {
+    fn parse(&self, _input: &str) -> Result<()> {
+        // Nothing to parse, the parts read the bytes as they come
+        Ok(())
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        let r = first_marker(input, self.params.packet)?
+            .ok_or_else(|| eyre!("no start-of-packet marker in the datastream"))?;
+
+        Ok(r.into())
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        let r = first_marker(input, self.params.message)?
+            .ok_or_else(|| eyre!("no start-of-message marker in the datastream"))?;
+
+        Ok(r.into())
+    }
+
+    fn configure(&mut self, args: &[String]) -> Result<()> {
+        aoc_core::configure(&mut self.params, args)
+    }
+}
@@ -1,30 +1,29 @@
-use std::collections::HashSet;
+use std::io::IsTerminal;
 
+use aoc_core::options::Options;
+use aoc_core::Solver;
 use eyre::Result;
-use itertools::Itertools;
 
 fn main() -> Result<()> {
-    let input = std::io::read_to_string(std::io::stdin())?;
+    let mut args = std::env::args().skip(1).collect();
+    let options = Options::take(&mut args)?;
+    aoc_core::set_quiet(options.quiet);
+    let mut solver = d06::Solution::default();
+    solver.configure(&args)?;
 
-    // PART 1
-    let chars = input.chars().collect::<Vec<_>>();
-    let r = chars
-        .windows(4)
-        .find_position(|w| w.iter().cloned().collect::<HashSet<_>>().len() == w.len())
-        .unwrap();
-    let r = r.0 + 4;
+    if solver.params.all {
+        // A piped datastream is searched while it is read
+        if options.input.is_none() && !std::io::stdin().is_terminal() {
+            return solver.print_markers(std::io::stdin().lock());
+        }
+        let input = options.read_input(6)?;
+        return solver.print_markers(input.as_bytes());
+    }
 
-    println!("{r}");
-
-    // PART 2
-    let r = chars
-        .windows(14)
-        .find_position(|w| w.iter().cloned().collect::<HashSet<_>>().len() == w.len())
-        .unwrap();
-    let r = r.0 + 14;
-
-    println!("{r}");
-
-    // println!("{r}");
-    Ok(())
+    let input = options.read_input(6)?;
+    if solver.params.frames {
+        print!("{}", solver.frame_table(&input));
+        return Ok(());
+    }
+    aoc_core::report(6, &solver, &input, options.format)
 }
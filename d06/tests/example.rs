@@ -0,0 +1,73 @@
+use aoc_core::{Answer, Solver};
+use d06::{Frame, Solution};
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part_1() {
+    assert_eq!(
+        Solution::default().part_1(EXAMPLE).unwrap(),
+        Answer::Number(7)
+    );
+}
+
+#[test]
+fn part_2() {
+    assert_eq!(
+        Solution::default().part_2(EXAMPLE).unwrap(),
+        Answer::Number(19)
+    );
+}
+
+#[test]
+fn all_markers() {
+    let mut found = Vec::new();
+    d06::markers("abcab\nxxab\r\nabcd".as_bytes(), &[3, 4], |m| {
+        found.push((m.line, m.position, m.size))
+    })
+    .unwrap();
+    assert_eq!(
+        found,
+        [
+            (1, 3, 3),
+            (1, 4, 3),
+            (1, 5, 3),
+            (2, 4, 3),
+            (3, 3, 3),
+            (3, 4, 3),
+            (3, 4, 4)
+        ]
+    );
+
+    // Equal sizes are told apart by their index
+    let mut found = Vec::new();
+    d06::markers("abcd".as_bytes(), &[4, 4], |m| found.push(m.index)).unwrap();
+    assert_eq!(found, [0, 1]);
+}
+
+#[test]
+fn window_size() {
+    let mut solution = Solution::default();
+    solution
+        .configure(&["--packet=2".to_string(), "--message=20".to_string()])
+        .unwrap();
+    assert_eq!(solution.part_1(EXAMPLE).unwrap(), Answer::Number(2));
+    assert!(solution.part_2(EXAMPLE).is_err());
+}
+
+#[test]
+fn frames() {
+    // Markers `abcd` and `bcde`, the search starting over after the first one
+    let frames = d06::frames(b"abcdaabbcdefgh", 4);
+    assert_eq!(
+        frames,
+        [Frame { offset: 4, len: 3 }, Frame { offset: 11, len: 3 }]
+    );
+
+    let decoded = Solution::default().decode(EXAMPLE);
+    let first: Vec<_> = decoded
+        .iter()
+        .map(|d| (d.packets[0].offset, d.messages[0].offset))
+        .collect();
+    assert_eq!(first, [(7, 19), (5, 23), (6, 23), (10, 29), (11, 26)]);
+}
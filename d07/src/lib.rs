@@ -0,0 +1,130 @@
+use aoc_core::{Answer, Solver};
+use clap::Parser;
+use eyre::{ensure, eyre, Result};
+
+use vfs::{Filesystem, Kind};
+
+pub mod vfs;
+
+/// Puzzle parameters, the defaults solve the real input
+#[derive(Clone, Debug, Parser)]
+pub struct Params {
+    /// Directories summed in part 1 are smaller than this
+    #[arg(long, default_value_t = 100000)]
+    pub max_size: usize,
+    /// Space the filesystem may use at most for the update to fit, in part 2
+    #[arg(long, default_value_t = 40000000)]
+    pub max_used: usize,
+    /// Print the total size of every directory instead of the answers
+    #[arg(long)]
+    pub du: bool,
+    /// Print the reconstructed hierarchy instead of the answers
+    #[arg(long)]
+    pub tree: bool,
+    /// Print the entries matching `--name`, `--larger`, `--smaller` and `--kind` instead of the
+    /// answers
+    #[arg(long)]
+    pub find: bool,
+    /// Only find entries with a name matching this pattern, `*` and `?` being wildcards
+    #[arg(long, requires = "find")]
+    pub name: Option<String>,
+    /// Only find entries larger than this, directories by their total size
+    #[arg(long, requires = "find")]
+    pub larger: Option<usize>,
+    /// Only find entries smaller than this
+    #[arg(long, requires = "find")]
+    pub smaller: Option<usize>,
+    /// Only find files or directories
+    #[arg(long, value_enum, requires = "find")]
+    pub kind: Option<Kind>,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self::parse_from([""])
+    }
+}
+
+#[derive(Default)]
+pub struct Solution {
+    pub params: Params,
+}
+
+impl Solution {
+    /// Total size and full path of every directory, one per line like `du`
+    pub fn du(&self, input: &str) -> Result<String> {
+        let fs = Filesystem::parse(input)?;
+        let lines: Vec<_> = fs
+            .du()
+            .into_iter()
+            .map(|(id, size)| format!("{size}\t{}", fs.path(id)))
+            .collect();
+        Ok(lines.join("\n"))
+    }
+
+    /// Full paths of the entries matching the `--find` predicates
+    pub fn find(&self, input: &str) -> Result<Vec<String>> {
+        let Params {
+            name,
+            larger,
+            smaller,
+            kind,
+            ..
+        } = &self.params;
+        let fs = Filesystem::parse(input)?;
+        let found = fs.find(|e, size| {
+            name.as_ref().is_none_or(|p| vfs::glob(p, &e.name))
+                && larger.is_none_or(|l| size > l)
+                && smaller.is_none_or(|s| size < s)
+                && kind.is_none_or(|k| e.kind() == k)
+        });
+        Ok(found.into_iter().map(|id| fs.path(id)).collect())
+    }
+
+    /// Drawing of the hierarchy
+    pub fn tree(&self, input: &str) -> Result<String> {
+        Ok(Filesystem::parse(input)?.tree())
+    }
+}
+
+impl Solver for Solution {
+    fn parse(&self, input: &str) -> Result<()> {
+        Filesystem::parse(input)?;
+
+        Ok(())
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        let r: usize = Filesystem::parse(input)?
+            .du()
+            .into_iter()
+            .map(|(_, size)| size)
+            .filter(|&size| size < self.params.max_size)
+            .sum();
+
+        Ok(r.into())
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        let fs = Filesystem::parse(input)?;
+        let used = fs.size(Filesystem::ROOT);
+        ensure!(
+            used > self.params.max_used,
+            "there is already enough space for the update"
+        );
+        let delta = used - self.params.max_used;
+        let r = fs
+            .du()
+            .into_iter()
+            .map(|(_, size)| size)
+            .filter(|&size| size > delta)
+            .min()
+            .ok_or_else(|| eyre!("no directory frees enough space for the update"))?;
+
+        Ok(r.into())
+    }
+
+    fn configure(&mut self, args: &[String]) -> Result<()> {
+        aoc_core::configure(&mut self.params, args)
+    }
+}
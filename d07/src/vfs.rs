@@ -0,0 +1,231 @@
+//! Filesystem rebuilt from the terminal session
+//!
+//! Entries live in an arena, each one knowing its parent, so that `cd ..` and full paths are
+//! simple lookups. An entry is always created after its parent, so walking the arena backwards
+//! visits children before their parent.
+
+use std::collections::BTreeMap;
+
+use aoc_core::debug;
+use aoc_core::parse::{self, ParseError};
+use clap::ValueEnum;
+use eyre::Result;
+
+pub type Id = usize;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Kind {
+    File,
+    Dir,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Content {
+    File(usize),
+    /// Entries by name, sorted like `ls` and `tree` list them
+    Dir(BTreeMap<String, Id>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    pub name: String,
+    pub parent: Option<Id>,
+    pub content: Content,
+}
+
+impl Entry {
+    pub fn kind(&self) -> Kind {
+        match self.content {
+            Content::File(_) => Kind::File,
+            Content::Dir(_) => Kind::Dir,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Filesystem {
+    entries: Vec<Entry>,
+    /// Total size of every entry, directories including everything below them
+    sizes: Vec<usize>,
+}
+
+impl Filesystem {
+    pub const ROOT: Id = 0;
+
+    /// Replay the terminal session
+    pub fn parse(input: &str) -> Result<Self> {
+        let at = |line: &str, e: ParseError| parse::rebase(input, line, e.into());
+        let mut entries = vec![Entry {
+            name: "/".to_string(),
+            parent: None,
+            content: Content::Dir(BTreeMap::new()),
+        }];
+        let mut cwd = Self::ROOT;
+        let mut lines = input.lines().peekable();
+
+        while let Some(cmd) = lines.next() {
+            match cmd.get(..4) {
+                Some("$ ls") => {
+                    while let Some(file) = lines.next_if(|l| !l.starts_with('$')) {
+                        let (size, name) =
+                            parse::split_once(file, file, " ").map_err(|e| at(file, e))?;
+                        let content = match size {
+                            "dir" => Content::Dir(BTreeMap::new()),
+                            s => Content::File(parse::number(file, s).map_err(|e| at(file, e))?),
+                        };
+                        let Content::Dir(children) = &entries[cwd].content else {
+                            unreachable!("only directories are entered");
+                        };
+                        // Listing a directory again finds the same entries
+                        if children.contains_key(name) {
+                            continue;
+                        }
+                        let id = entries.len();
+                        entries.push(Entry {
+                            name: name.to_string(),
+                            parent: Some(cwd),
+                            content,
+                        });
+                        if let Content::Dir(children) = &mut entries[cwd].content {
+                            children.insert(name.to_string(), id);
+                        }
+                    }
+                }
+                Some("$ cd") => {
+                    let dest = parse::tag(cmd, cmd, "$ cd ").map_err(|e| at(cmd, e))?;
+                    debug!("{dest}");
+                    cwd = match dest {
+                        ".." => entries[cwd].parent.unwrap_or(Self::ROOT),
+                        "/" => Self::ROOT,
+                        d => match &entries[cwd].content {
+                            Content::Dir(children) => children
+                                .get(d)
+                                .copied()
+                                .filter(|&id| entries[id].kind() == Kind::Dir),
+                            Content::File(_) => None,
+                        }
+                        .ok_or_else(|| at(cmd, ParseError::new(cmd, d, "a listed directory")))?,
+                    };
+                }
+                _ => return Err(at(cmd, ParseError::new(cmd, cmd, "`$ cd` or `$ ls`"))),
+            }
+        }
+
+        let mut sizes: Vec<_> = entries
+            .iter()
+            .map(|e| match e.content {
+                Content::File(size) => size,
+                Content::Dir(_) => 0,
+            })
+            .collect();
+        for (id, e) in entries.iter().enumerate().skip(1).rev() {
+            sizes[e.parent.unwrap()] += sizes[id];
+        }
+
+        Ok(Self { entries, sizes })
+    }
+
+    pub fn entry(&self, id: Id) -> &Entry {
+        &self.entries[id]
+    }
+
+    /// Size of a file, or total size of a directory
+    pub fn size(&self, id: Id) -> usize {
+        self.sizes[id]
+    }
+
+    /// Full path, from the root
+    pub fn path(&self, id: Id) -> String {
+        let mut names = Vec::new();
+        let mut at = Some(id);
+        while let Some(id) = at.filter(|&id| id != Self::ROOT) {
+            names.push(self.entries[id].name.as_str());
+            at = self.entries[id].parent;
+        }
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+
+    /// Entry at a full path
+    pub fn lookup(&self, path: &str) -> Option<Id> {
+        path.split('/')
+            .filter(|n| !n.is_empty())
+            .try_fold(Self::ROOT, |id, name| match &self.entries[id].content {
+                Content::Dir(children) => children.get(name).copied(),
+                Content::File(_) => None,
+            })
+    }
+
+    /// Every entry below `id`, `id` included, parents before their children
+    fn walk(&self, id: Id) -> Vec<(Id, usize)> {
+        let mut walk = Vec::new();
+        let mut stack = vec![(id, 0)];
+        while let Some((id, depth)) = stack.pop() {
+            walk.push((id, depth));
+            if let Content::Dir(children) = &self.entries[id].content {
+                stack.extend(children.values().rev().map(|&c| (c, depth + 1)));
+            }
+        }
+        walk
+    }
+
+    /// Directories with their total size, like `du`: children before their parent
+    pub fn du(&self) -> Vec<(Id, usize)> {
+        fn visit(fs: &Filesystem, id: Id, dirs: &mut Vec<(Id, usize)>) {
+            if let Content::Dir(children) = &fs.entries[id].content {
+                children.values().for_each(|&c| visit(fs, c, dirs));
+                dirs.push((id, fs.sizes[id]));
+            }
+        }
+
+        let mut dirs = Vec::new();
+        visit(self, Self::ROOT, &mut dirs);
+        dirs
+    }
+
+    /// Entries matching the predicate, in the order of [`Filesystem::tree`]
+    pub fn find(&self, mut pred: impl FnMut(&Entry, usize) -> bool) -> Vec<Id> {
+        self.walk(Self::ROOT)
+            .into_iter()
+            .map(|(id, _)| id)
+            .filter(|&id| pred(&self.entries[id], self.sizes[id]))
+            .collect()
+    }
+
+    /// Drawing of the hierarchy, in the format of the puzzle
+    pub fn tree(&self) -> String {
+        let lines: Vec<_> = self
+            .walk(Self::ROOT)
+            .into_iter()
+            .map(|(id, depth)| {
+                let e = &self.entries[id];
+                let indent = "  ".repeat(depth);
+                match e.content {
+                    Content::File(size) => format!("{indent}- {} (file, size={size})", e.name),
+                    Content::Dir(_) => format!("{indent}- {} (dir)", e.name),
+                }
+            })
+            .collect();
+        lines.join("\n")
+    }
+}
+
+/// Match a name against a pattern where `*` stands for any text and `?` for any character
+pub fn glob(pattern: &str, name: &str) -> bool {
+    let (p, n): (Vec<char>, Vec<char>) = (pattern.chars().collect(), name.chars().collect());
+    // matched[j]: the pattern read so far matches the first `j` characters of the name
+    let mut matched = vec![false; n.len() + 1];
+    matched[0] = true;
+    for &c in &p {
+        let mut next = vec![false; n.len() + 1];
+        for j in 0..=n.len() {
+            next[j] = match c {
+                '*' => matched[j] || (j > 0 && next[j - 1]),
+                '?' => j > 0 && matched[j - 1],
+                c => j > 0 && matched[j - 1] && n[j - 1] == c,
+            };
+        }
+        matched = next;
+    }
+    matched[n.len()]
+}
@@ -0,0 +1,14 @@
+use aoc_core::{Answer, Solver};
+use d07::Solution;
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part_1() {
+    assert_eq!(Solution::default().part_1(EXAMPLE).unwrap(), Answer::Number(95437));
+}
+
+#[test]
+fn part_2() {
+    assert_eq!(Solution::default().part_2(EXAMPLE).unwrap(), Answer::Number(24933642));
+}
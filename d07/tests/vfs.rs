@@ -0,0 +1,81 @@
+use d07::vfs::{glob, Content, Filesystem, Kind};
+use d07::Solution;
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn du() {
+    let solution = Solution::default();
+    assert_eq!(
+        solution.du(EXAMPLE).unwrap(),
+        "584\t/a/e\n94853\t/a\n24933642\t/d\n48381165\t/"
+    );
+}
+
+#[test]
+fn paths() {
+    let fs = Filesystem::parse(EXAMPLE).unwrap();
+    let i = fs.lookup("/a/e/i").unwrap();
+    assert_eq!(fs.path(i), "/a/e/i");
+    assert_eq!(fs.entry(i).content, Content::File(584));
+    assert_eq!(fs.path(Filesystem::ROOT), "/");
+    assert_eq!(fs.lookup("/a/missing"), None);
+}
+
+#[test]
+fn tree() {
+    let expected = "\
+- / (dir)
+  - a (dir)
+    - e (dir)
+      - i (file, size=584)
+    - f (file, size=29116)
+    - g (file, size=2557)
+    - h.lst (file, size=62596)
+  - b.txt (file, size=14848514)
+  - c.dat (file, size=8504156)
+  - d (dir)
+    - d.ext (file, size=5626152)
+    - d.log (file, size=8033020)
+    - j (file, size=4060174)
+    - k (file, size=7214296)";
+    assert_eq!(Solution::default().tree(EXAMPLE).unwrap(), expected);
+}
+
+#[test]
+fn find() {
+    let mut solution = Solution::default();
+    solution.params.name = Some("*.*".to_string());
+    solution.params.larger = Some(8_000_000);
+    assert_eq!(
+        solution.find(EXAMPLE).unwrap(),
+        ["/b.txt", "/c.dat", "/d/d.log"]
+    );
+
+    let mut solution = Solution::default();
+    solution.params.kind = Some(Kind::Dir);
+    solution.params.smaller = Some(100_000);
+    assert_eq!(solution.find(EXAMPLE).unwrap(), ["/a", "/a/e"]);
+}
+
+#[test]
+fn globs() {
+    assert!(glob("d.*", "d.log"));
+    assert!(glob("?", "e"));
+    assert!(glob("*", ""));
+    assert!(!glob("d.?", "d.log"));
+    assert!(!glob("*.txt", "b.dat"));
+}
+
+#[test]
+fn relisted_directory() {
+    let session = "$ cd /\n$ ls\n1 a\ndir b\n$ cd b\n$ ls\n2 c\n$ cd ..\n$ ls\n1 a\ndir b\n";
+    let fs = Filesystem::parse(session).unwrap();
+    assert_eq!(fs.size(Filesystem::ROOT), 3);
+}
+
+#[test]
+fn unknown_directory() {
+    let err = Filesystem::parse("$ cd /\n$ ls\ndir a\n$ cd b\n").unwrap_err();
+    assert!(err.to_string().contains("a listed directory"), "{err}");
+}
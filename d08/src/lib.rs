@@ -0,0 +1,101 @@
+use std::iter::once;
+
+use aoc_core::{Answer, Solver};
+use eyre::Result;
+use aoc_grid::{Grid, Pos, DIRS_4};
+
+struct TreeLine {
+    grid: Grid<u8>,
+    viz: Grid<bool>,
+}
+
+impl TreeLine {
+    fn parse(s: &str) -> Result<Self> {
+        let grid = Grid::parse(s, "a tree height", |c| c.to_digit(10).map(|d| d as u8))?;
+        let viz = Grid::from_elem(grid.height(), grid.width(), false);
+
+        Ok(Self {
+            grid,
+            viz,
+        })
+    }
+
+    fn height_viz(&mut self) {
+        let (h, w) = (self.grid.height(), self.grid.width());
+        let starts = (0..h)
+            .flat_map(|i| [((i, 0), (0, 1)), ((i, w - 1), (0, -1))])
+            .chain((0..w).flat_map(|j| [((0, j), (1, 0)), ((h - 1, j), (-1, 0))]));
+
+        for (start, dir) in starts {
+            let mut frontier = 0;
+            for p in once(start).chain(self.grid.ray(start, dir)) {
+                if self.grid[p] > frontier {
+                    frontier = self.grid[p];
+                    self.viz[p] = true;
+                }
+            }
+        }
+    }
+
+    fn visibility(&mut self) {
+        self.height_viz();
+
+        let (h, w) = (self.grid.height(), self.grid.width());
+        for (i, j) in self.grid.positions() {
+            if i == 0 || j == 0 || i == h - 1 || j == w - 1 {
+                self.viz[(i, j)] = true;
+            }
+        }
+    }
+
+    fn point_viz(&self, coord: Pos) -> usize {
+        DIRS_4.into_iter()
+            .map(|d| self.line_viz(coord, self.grid.ray(coord, d)))
+            .product()
+    }
+
+    fn line_viz(&self, coord: Pos, line: impl IntoIterator<Item=Pos>) -> usize {
+        let h = self.grid[coord];
+        let mut cnt = 0;
+        for c in line {
+            if self.grid[c] < h {
+                cnt += 1;
+            } else {
+                cnt += 1;
+                break;
+            }
+        }
+        cnt
+    }
+
+    fn count_viz(&self) -> usize {
+        self.viz.iter().filter(|&v| *v).count()
+    }
+}
+
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn parse(&self, input: &str) -> Result<()> {
+        TreeLine::parse(input)?;
+
+        Ok(())
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        let mut t = TreeLine::parse(input)?;
+        t.visibility();
+
+        let r = t.count_viz();
+
+        Ok(r.into())
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        let t = TreeLine::parse(input)?;
+        let r = t.grid.positions().map(|p| t.point_viz(p)).max().unwrap();
+
+        Ok(r.into())
+    }
+}
@@ -0,0 +1,14 @@
+use aoc_core::{Answer, Solver};
+use d08::Solution;
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part_1() {
+    assert_eq!(Solution.part_1(EXAMPLE).unwrap(), Answer::Number(21));
+}
+
+#[test]
+fn part_2() {
+    assert_eq!(Solution.part_2(EXAMPLE).unwrap(), Answer::Number(8));
+}
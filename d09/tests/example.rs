@@ -0,0 +1,21 @@
+use aoc_core::{Answer, Solver};
+use d09::Solution;
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+const LARGER: &str = include_str!("../fixtures/example-larger.txt");
+
+#[test]
+fn part_1() {
+    assert_eq!(Solution.part_1(EXAMPLE).unwrap(), Answer::Number(13));
+}
+
+#[test]
+fn part_2() {
+    assert_eq!(Solution.part_2(EXAMPLE).unwrap(), Answer::Number(1));
+}
+
+#[test]
+fn part_2_larger() {
+    assert_eq!(Solution.part_2(LARGER).unwrap(), Answer::Number(36));
+}
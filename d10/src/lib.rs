@@ -0,0 +1,100 @@
+use aoc_core::parse::{self, ParseError};
+use aoc_core::{Answer, Solver};
+use aoc_grid::Grid;
+use eyre::Result;
+
+enum Op {
+    Noop,
+    Addx(isize),
+}
+
+impl Op {
+    pub fn parse(s: &str) -> Result<Self> {
+        Ok(match s.split_once(' ') {
+            None if s == "noop" => Op::Noop,
+            None if s == "addx" => return Err(ParseError::new(s, &s[s.len()..], "a number").into()),
+            Some(("addx", v)) => Op::Addx(parse::number(s, v)?),
+            _ => return Err(ParseError::new(s, s, "`noop` or `addx`").into()),
+        })
+    }
+
+    pub fn schedule(self) -> (Self, usize) {
+        match self {
+            Op::Noop => (self, 1),
+            Op::Addx(_) => (self, 2),
+        }
+    }
+}
+
+struct Processor<I: Iterator<Item = Op>> {
+    code: I,
+    rx: isize,
+    issuer: Option<(Op, usize)>,
+}
+
+impl<I: Iterator<Item = Op>> Processor<I> {
+    fn new(code: I) -> Self {
+        Self {
+            code,
+            rx: 1,
+            issuer: None,
+        }
+    }
+}
+
+impl<I: Iterator<Item = Op>> Iterator for Processor<I> {
+    type Item = isize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.issuer.is_none() {
+            self.issuer = Some(self.code.next().map(Op::schedule)?);
+        }
+        let (op, mut rem) = self.issuer.take().unwrap();
+        
+        let ret = self.rx; // Return value before end of execution
+        rem -= 1;
+        match (op, rem) {
+            (Op::Addx(v), 0) => {
+                self.rx += v;
+            }
+            (Op::Noop, 0) => {}
+            incomplete => self.issuer = Some(incomplete),
+        }
+        Some(ret)
+    }
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn parse(&self, input: &str) -> Result<()> {
+        parse::lines(input, Op::parse).collect::<Result<Vec<_>>>()?;
+
+        Ok(())
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        let instr = parse::lines(input, Op::parse).collect::<Result<Vec<_>>>()?.into_iter();
+
+        let r: isize = Processor::new(instr)
+            .enumerate()
+            .skip(19)
+            .step_by(40)
+            .map(|(i, v)| (i as isize + 1) * v)
+            .sum();
+
+        Ok(r.into())
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        let instr = parse::lines(input, Op::parse).collect::<Result<Vec<_>>>()?.into_iter();
+
+        let mut screen = Grid::from_elem(6, 40, ' ');
+        Processor::new(instr)
+            .enumerate()
+            .map(|(i, x)| ((i / 40, i % 40), x))
+            .for_each(|(coord, x)| if (x - coord.1 as isize).abs() <= 1 { screen[coord] = '#' });
+
+        Ok(screen.to_string().into())
+    }
+}
@@ -0,0 +1,30 @@
+use aoc_core::{Answer, Solver};
+use d10::Solution;
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+/// Lit pixels are `#`, dark ones written `.` here to keep the trailing ones visible
+const SCREEN: &str = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+
+#[test]
+fn part_1() {
+    assert_eq!(Solution.part_1(EXAMPLE).unwrap(), Answer::Number(13140));
+}
+
+#[test]
+fn part_2() {
+    let screen = Solution.part_2(EXAMPLE).unwrap().to_string();
+    assert_eq!(screen.replace(' ', "."), SCREEN);
+}
+
+#[test]
+fn missing_operand() {
+    let e = Solution.parse("noop\naddx\n").unwrap_err();
+    assert_eq!(e.to_string(), "line 2, column 5: expected a number, found end of line");
+}
@@ -0,0 +1,203 @@
+#![allow(unused_imports)]
+
+use aoc_core::parse::{self, ParseError};
+use aoc_core::{debug, Answer, Solver};
+use clap::Parser;
+use eyre::{bail, ensure, eyre, Result};
+use itertools::Itertools;
+
+#[derive(Debug)]
+enum Op {
+    Sq,
+    Add(usize),
+    Mul(usize)
+}
+
+impl Op {
+    /// Parse the `old * 19` expression `s`, a slice of `input`
+    pub fn parse(input: &str, s: &str) -> Result<Self, ParseError> {
+        let rest = parse::tag(input, s, "old ")?;
+        let (op, rhs) = parse::split_once(input, rest, " ")?;
+
+        match (op, rhs) {
+            ("*", "old") => Ok(Self::Sq),
+            ("*", d) => Ok(Self::Mul(parse::number(input, d)?)),
+            ("+", d) => Ok(Self::Add(parse::number(input, d)?)),
+            _ => Err(ParseError::new(input, op, "`*` or `+`")),
+        }
+    }
+
+    pub fn apply(&self, rhs: usize) -> usize {
+        match self {
+            Op::Sq => rhs * rhs,
+            Op::Add(a) => rhs + a,
+            Op::Mul(a) => rhs * a,
+        }
+    }
+}
+
+struct Message {
+    dest: usize,
+    value: usize,
+}
+
+#[derive(Debug)]
+struct Monke {
+    items: Vec<usize>,
+    op: Op,
+    divisor: usize,
+    dest_true: usize,
+    dest_false: usize,
+    inspect_count: usize,
+}
+
+impl Monke {
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut lines = s.lines();
+        // Content of the next line after its (indented) `prefix`
+        let mut line = |prefix: &str| match lines.next() {
+            Some(l) => parse::tag(s, l.trim_start(), prefix),
+            None => Err(ParseError::new(s, &s[s.len()..], format!("`{prefix}`"))),
+        };
+
+        line("Monkey ")?;
+        let items = line("Starting items: ")?;
+        let items = items.split(", ").map(|d| parse::number(s, d)).collect::<Result<_, _>>()?;
+
+        let op = Op::parse(s, line("Operation: new = ")?)?;
+
+        let div = line("Test: divisible by ")?;
+        let div = match parse::number(s, div)? {
+            0 => return Err(ParseError::new(s, div, "a divisor of at least 1").into()),
+            d => d,
+        };
+        let dest_true = parse::number(s, line("If true: throw to monkey ")?)?;
+        let dest_false = parse::number(s, line("If false: throw to monkey ")?)?;
+
+        Ok(Self {
+            items,
+            op,
+            divisor: div,
+            dest_true,
+            dest_false,
+            inspect_count: 0,
+        })
+    }
+
+    pub fn inspect_all(&mut self, relief: usize) -> impl IntoIterator<Item=Message> {
+        self.inspect_count += self.items.len();
+        self.items.drain(..)
+            .map(|o| self.op.apply(o) / relief)
+            .map(|value| if value % self.divisor == 0 {
+                Message{ dest: self.dest_true, value}
+            } else {
+                Message{ dest: self.dest_false, value}
+            })
+            .collect::<Vec<_>>()
+    }
+
+    pub fn inspect_all_2(&mut self, modulo: usize) -> impl IntoIterator<Item=Message> {
+        self.inspect_count += self.items.len();
+        self.items.drain(..)
+            .map(|o| self.op.apply(o) % modulo)
+            .map(|value| if value % self.divisor == 0 {
+                Message{ dest: self.dest_true, value}
+            } else {
+                Message{ dest: self.dest_false, value}
+            })
+            .collect::<Vec<_>>()
+    }
+}
+
+
+fn parse_monkeys(input: &str) -> Result<Vec<Monke>> {
+    let monkeys = input.split("\n\n")
+        .map(|m| Monke::parse(m).map_err(|e| parse::rebase(input, m, e)))
+        .collect::<Result<Vec<_>>>()?;
+    ensure!(monkeys.len() >= 2, "expected at least two monkeys, found {}", monkeys.len());
+
+    for (i, m) in monkeys.iter().enumerate() {
+        if let Some(dest) = [m.dest_true, m.dest_false].into_iter().find(|&d| d >= monkeys.len()) {
+            bail!("monkey {i} throws to monkey {dest}, which does not exist");
+        }
+    }
+    Ok(monkeys)
+}
+
+/// Puzzle parameters, the defaults solve the real input
+#[derive(Clone, Debug, Parser)]
+pub struct Params {
+    /// Rounds played in part 1
+    #[arg(long, default_value_t = 20)]
+    pub rounds_1: usize,
+    /// Rounds played in part 2
+    #[arg(long, default_value_t = 10000)]
+    pub rounds_2: usize,
+    /// Worry levels are divided by this after each inspection in part 1
+    #[arg(long, default_value_t = 3)]
+    pub relief: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self::parse_from([""])
+    }
+}
+
+#[derive(Default)]
+pub struct Solution {
+    pub params: Params,
+}
+
+impl Solver for Solution {
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_monkeys(input)?;
+
+        Ok(())
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        let mut monkeys = parse_monkeys(input)?;
+        ensure!(self.params.relief > 0, "the relief must be positive");
+
+        for _ in 0..self.params.rounds_1 {
+            for i in 0..monkeys.len() {
+                for message in monkeys[i].inspect_all(self.params.relief) {
+                    monkeys[message.dest].items.push(message.value);
+                }
+            }
+        }
+
+        monkeys.sort_by_key(|m| m.inspect_count);
+
+        debug!("{monkeys:?}");
+
+        Ok((monkeys[monkeys.len()-1].inspect_count * monkeys[monkeys.len()-2].inspect_count).into())
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        let mut monkeys = parse_monkeys(input)?;
+
+        let modulo = monkeys.iter()
+            .try_fold(1usize, |acc, m| acc.checked_mul(m.divisor))
+            .ok_or_else(|| eyre!("the product of the divisors overflows"))?;
+
+        for _ in 0..self.params.rounds_2 {
+            for i in 0..monkeys.len() {
+                for message in monkeys[i].inspect_all_2(modulo) {
+                    monkeys[message.dest].items.push(message.value);
+                }
+            }
+        }
+
+        monkeys.sort_by_key(|m| m.inspect_count);
+
+        debug!("{monkeys:#?}");
+
+        Ok((monkeys[monkeys.len()-1].inspect_count * monkeys[monkeys.len()-2].inspect_count).into())
+    }
+
+    fn configure(&mut self, args: &[String]) -> Result<()> {
+        aoc_core::configure(&mut self.params, args)
+    }
+}
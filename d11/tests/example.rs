@@ -0,0 +1,35 @@
+use aoc_core::{Answer, Solver};
+use d11::Solution;
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part_1() {
+    assert_eq!(Solution::default().part_1(EXAMPLE).unwrap(), Answer::Number(10605));
+}
+
+#[test]
+fn part_2() {
+    assert_eq!(Solution::default().part_2(EXAMPLE).unwrap(), Answer::Number(2713310158));
+}
+
+#[test]
+fn single_monkey() {
+    let input = EXAMPLE.split("\n\n").next().unwrap();
+    let e = Solution::default().part_1(input).unwrap_err();
+    assert_eq!(e.to_string(), "expected at least two monkeys, found 1");
+}
+
+#[test]
+fn zero_divisor() {
+    let input = EXAMPLE.replacen("divisible by 23", "divisible by 0", 1);
+    let e = Solution::default().part_1(&input).unwrap_err();
+    assert_eq!(e.to_string(), "line 4, column 22: expected a divisor of at least 1, found `0`");
+}
+
+#[test]
+fn divisors_overflow() {
+    let input = EXAMPLE.replace("divisible by 23", &format!("divisible by {}", usize::MAX));
+    let e = Solution::default().part_2(&input).unwrap_err();
+    assert_eq!(e.to_string(), "the product of the divisors overflows");
+}
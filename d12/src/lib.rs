@@ -0,0 +1,88 @@
+#![allow(unused_imports)]
+
+use aoc_core::{debug, Answer, Solver};
+use itertools::Itertools;
+use aoc_grid::{Grid, Pos};
+use eyre::{eyre, Result};
+
+use petgraph::algo::dijkstra;
+use petgraph::data::FromElements;
+use petgraph::prelude::GraphMap;
+use petgraph::{Directed, Graph, Undirected};
+
+struct Map {
+    grid: Grid<u8>,
+}
+
+impl Map {
+    pub fn parse(s: &str) -> Result<(Self, Pos, Pos)> {
+        let chars = Grid::parse(s, "a lowercase letter, `S` or `E`", |c| {
+            matches!(c, 'a'..='z' | 'S' | 'E').then_some(c)
+        })?;
+        let start = chars.position(|&c| c == 'S').ok_or_else(|| eyre!("no start `S` in the map"))?;
+        let end = chars.position(|&c| c == 'E').ok_or_else(|| eyre!("no destination `E` in the map"))?;
+
+        let grid = chars.map(|&c| match c {
+            'S' => 0,
+            'E' => 25,
+            c => c as u8 - b'a',
+        });
+
+        Ok((Self { grid }, start, end))
+    }
+
+    /// Edges from every cell to the neighbours for which `cond(neighbour, cell)` holds
+    pub fn compute_connections(
+        &self,
+        cond: impl Fn(u8, u8) -> bool,
+    ) -> GraphMap<Pos, i32, Directed> {
+        let cond = &cond;
+        let iter = self.grid.indexed_iter().flat_map(|(i, &v)| {
+            self.grid
+                .neighbours_4(i)
+                .filter(move |&n| (cond)(self.grid[n], v))
+                .map(move |n| (i, n))
+        });
+
+        GraphMap::<_, _, Directed>::from_edges(iter)
+    }
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn parse(&self, input: &str) -> Result<()> {
+        Map::parse(input)?;
+
+        Ok(())
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        let (map, s, e) = Map::parse(input)?;
+        debug!("s: {s:?}, e: {e:?}");
+
+        // Climb at most one step up
+        let graph = map.compute_connections(|next, cur| next <= cur + 1);
+        let res = dijkstra(&graph, s, Some(e), |_| 1);
+        let r = *res.get(&e).ok_or_else(|| eyre!("no path to the destination"))?;
+
+        Ok(r.into())
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        let (map, _, e) = Map::parse(input)?;
+
+        // Walk back down from the destination
+        let graph = map.compute_connections(|prev, cur| cur <= prev + 1);
+        let res = dijkstra(&graph, e, None, |_| 1);
+
+        let r = res
+            .into_iter()
+            .filter(|r| map.grid[r.0] == 0)
+            .min_by_key(|t| t.1)
+            .ok_or_else(|| eyre!("no path from any `a` to the destination"))?
+            .1;
+
+        Ok(r.into())
+    }
+}
@@ -0,0 +1,195 @@
+//! Note that likely a better implementation would have been to parse the packet as a list of (value, depth)
+
+#![allow(unused_imports)]
+
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+
+use aoc_core::parse::{self, NomResult, ParseError};
+use aoc_core::{Answer, Solver};
+use eyre::Result;
+use itertools::Itertools;
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::one_of;
+use nom::combinator::*;
+use nom::multi::many1;
+use nom::sequence::terminated;
+use nom::error::context;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Tok {
+    Enter,
+    Exit,
+    Value(u32),
+}
+
+impl Debug for Tok {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for Tok {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tok::Enter => write!(f, "["),
+            Tok::Exit => write!(f, "]"),
+            Tok::Value(v) => write!(f, "{v}"),
+        }
+    }
+}
+
+impl FromStr for Tok {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "[" => Self::Enter,
+            "]" => Self::Exit,
+            a => Self::Value(a.parse::<u32>().map_err(|_| ())?),
+        })
+    }
+}
+
+fn decimal(input: &str) -> NomResult<'_, &str> {
+    recognize(many1(one_of("0123456789")))(input)
+}
+
+fn parse_packet(s: &str) -> NomResult<'_, Vec<Tok>> {
+    many1(map_res(
+        terminated(
+            context("`[`, `]` or a number", alt((recognize(one_of("[]")), decimal))),
+            opt(tag(",")),
+        ),
+        Tok::from_str,
+    ))(s)
+}
+
+fn packet(line: &str) -> Result<Vec<Tok>> {
+    Ok(parse::nom_line(line, parse_packet)?)
+}
+
+#[derive(Debug)]
+struct Cruncher {
+    left: Vec<Tok>,
+    right: Vec<Tok>,
+    l: usize,
+    r: usize,
+}
+
+impl Cruncher {
+    fn new(left: Vec<Tok>, right: Vec<Tok>) -> Self {
+        Self {
+            left,
+            right,
+            l: 0,
+            r: 0,
+        }
+    }
+
+    /// Parse a pair of packets from `s`, a slice of `input`
+    fn parse(input: &str, s: &str) -> Result<Self> {
+        let mut lines = s.lines();
+        let mut next = || match lines.next() {
+            Some(l) => packet(l).map_err(|e| parse::rebase(input, l, e)),
+            None => Err(ParseError::new(input, &s[s.len()..], "a packet").into()),
+        };
+        Ok(Self::new(next()?, next()?))
+    }
+
+    fn cur(&self) -> (Tok, Tok) {
+        (self.left[self.l], self.right[self.r])
+    }
+
+    #[inline(always)]
+    fn step_compare(&mut self) -> Ordering {
+        self.l += 1;
+        self.r += 1;
+        self.compare()
+    }
+
+    fn compare(&mut self) -> Ordering {
+        if self.l == self.left.len() && self.r == self.right.len() {
+            return Ordering::Equal;
+        }
+        if self.r == self.right.len() {
+            return Ordering::Greater;
+        }
+        match self.cur() {
+            (Tok::Value(l), Tok::Value(r)) => match l.cmp(&r) {
+                std::cmp::Ordering::Less => Ordering::Less,
+                std::cmp::Ordering::Greater => Ordering::Greater,
+                std::cmp::Ordering::Equal => self.step_compare(),
+            },
+            (Tok::Value(_) | Tok::Enter, Tok::Exit) => Ordering::Greater,
+            (Tok::Exit, Tok::Value(_) | Tok::Enter) => Ordering::Less,
+            (Tok::Exit, Tok::Exit) => self.step_compare(),
+            (Tok::Enter, Tok::Enter) => self.step_compare(),
+            (Tok::Enter, Tok::Value(_)) => {
+                self.right.insert(self.r, Tok::Enter);
+                self.right.insert(self.r + 2, Tok::Exit);
+                self.compare()
+            }
+            (Tok::Value(_), Tok::Enter) => {
+                self.left.insert(self.l, Tok::Enter);
+                self.left.insert(self.l + 2, Tok::Exit);
+                self.compare()
+            }
+        }
+    }
+}
+
+/// Pairs of packets separated by empty lines
+fn pairs(input: &str) -> Result<Vec<Cruncher>> {
+    input
+        .split("\n\n")
+        .map(|s| Cruncher::parse(input, s))
+        .collect()
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn parse(&self, input: &str) -> Result<()> {
+        pairs(input)?;
+
+        Ok(())
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        let r = pairs(input)?
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, mut c)| {
+                if c.compare().is_le() {
+                    Some(i + 1)
+                } else {
+                    None
+                }
+            })
+            .sum::<usize>();
+
+        Ok(r.into())
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        let mut v = parse::lines(input, |l| if l.is_empty() { Ok(None) } else { packet(l).map(Some) })
+            .filter_map(Result::transpose)
+            .chain(["[[2]]", "[[6]]"].map(packet))
+            .collect::<Result<Vec<_>>>()?;
+
+        v.sort_by(|a, b| {
+            let mut c = Cruncher::new(a.clone(), b.clone()); // Inefficient cloning!
+            c.compare()
+        });
+
+        let m1 = packet("[[2]]")?;
+        let m2 = packet("[[6]]")?;
+        let a = v.iter().find_position(move |&q| *q == m1).unwrap().0 + 1;
+        let b = v.iter().find_position(move |&q| *q == m2).unwrap().0 + 1;
+
+        Ok((a * b).into())
+    }
+}
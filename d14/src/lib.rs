@@ -0,0 +1,194 @@
+#![allow(unused_imports)]
+
+use std::collections::HashMap;
+
+use aoc_core::parse::{self, NomResult};
+use aoc_core::{debug, Answer, Solver};
+use clap::Parser;
+use eyre::{bail, ensure, Result};
+use itertools::Itertools;
+use nom::bytes::complete::tag;
+use nom::bytes::*;
+use nom::character::complete::digit1;
+use nom::combinator::*;
+use nom::error::context;
+use nom::multi::*;
+use nom::sequence::separated_pair;
+
+// Input format:
+// 498,4 -> 498,6 -> 496,6
+// 503,4 -> 502,4 -> 502,9 -> 494,9
+
+type Point = (isize, isize);
+
+fn decimal(s: &str) -> NomResult<'_, isize> {
+    context("a number", map_res(digit1, str::parse))(s)
+}
+
+fn point(s: &str) -> NomResult<'_, Point> {
+    separated_pair(decimal, context("`,`", tag(",")), decimal)(s)
+}
+
+fn path(s: &str) -> NomResult<'_, Vec<Point>> {
+    separated_list1(tag(" -> "), cut(point))(s)
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    #[default]
+    None,
+    Block,
+    Sand,
+}
+
+#[derive(Default)]
+struct Map {
+    grid: HashMap<Point, Tile>,
+    floor: isize,
+}
+
+impl Map {
+    fn add_segment(&mut self, a: Point, b: Point) {
+        match (a.0 == b.0, a.1 == b.1) {
+            (true, true) => {
+                self.grid.insert(a, Tile::Block);
+            }
+            (true, false) => {
+                for j in (a.1.min(b.1))..=(a.1.max(b.1)) {
+                    self.grid.insert((a.0, j), Tile::Block);
+                }
+            }
+            (false, true) => {
+                for i in (a.0.min(b.0))..=(a.0.max(b.0)) {
+                    self.grid.insert((i, a.1), Tile::Block);
+                }
+            }
+            (false, false) => panic!("invalid path"),
+        }
+        self.floor = self.floor.max(a.1).max(b.1);
+    }
+
+    fn is_free(&self, p: Point) -> bool {
+        self.grid.get(&p).is_none_or(|&t| t == Tile::None)
+    }
+
+    /// Check that sand can be poured from `source` at all
+    fn check_source(&self, source: Point) -> Result<()> {
+        let (x, y) = source;
+        ensure!(self.is_free(source), "the source {x},{y} is on a rock");
+        ensure!(
+            y < self.floor,
+            "the source {x},{y} must be above the lowest rock, at y={}",
+            self.floor
+        );
+        Ok(())
+    }
+
+    /// Pour one unit of sand from `source`, resting on `floor` if there is one
+    fn pour(&mut self, source: Point, floor: Option<isize>) -> Fall {
+        if !self.is_free(source) {
+            debug!("point is blocked!");
+            return Fall::Blocked;
+        }
+
+        let mut p = source;
+        loop {
+            match floor {
+                Some(floor) if p.1 + 1 == floor => break,
+                None if p.1 >= self.floor => return Fall::Abyss,
+                _ => (),
+            }
+            let next = [(p.0, p.1 + 1), (p.0 - 1, p.1 + 1), (p.0 + 1, p.1 + 1)]
+                .into_iter()
+                .find(|&q| self.is_free(q));
+            match next {
+                Some(q) => p = q,
+                None => break,
+            }
+        }
+
+        self.grid.insert(p, Tile::Sand);
+        Fall::Rest
+    }
+}
+
+/// Where a unit of sand ends up
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Fall {
+    Rest,
+    /// Below the lowest rock, with no floor to stop it
+    Abyss,
+    /// The source is covered, nothing more can be poured
+    Blocked,
+}
+
+fn parse_map(input: &str) -> Result<Map> {
+    let paths: Vec<_> = parse::lines(input, |l| Ok(parse::nom_line(l, path)?))
+        .collect::<Result<_>>()?;
+    let mut map = Map::default();
+    for (i, p) in paths.iter().enumerate() {
+        if let Some(w) = p.windows(2).find(|w| w[0].0 != w[1].0 && w[0].1 != w[1].1) {
+            bail!("line {}: diagonal segment from {:?} to {:?}", i + 1, w[0], w[1]);
+        }
+        p.windows(2).for_each(|w| map.add_segment(w[0], w[1]));
+    }
+    Ok(map)
+}
+
+/// Parse a `x,y` point from the command line
+fn parse_point(s: &str) -> Result<Point> {
+    Ok(parse::nom_line(s, point)?)
+}
+
+/// Puzzle parameters, the defaults solve the real input
+#[derive(Clone, Debug, Parser)]
+pub struct Params {
+    /// Point the sand is poured from, `x,y`
+    #[arg(long, default_value = "500,0", value_parser = parse_point)]
+    pub source: Point,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self::parse_from([""])
+    }
+}
+
+#[derive(Default)]
+pub struct Solution {
+    pub params: Params,
+}
+
+impl Solver for Solution {
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_map(input)?;
+
+        Ok(())
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        let mut map = parse_map(input)?;
+
+        map.check_source(self.params.source)?;
+
+        while map.pour(self.params.source, None) == Fall::Rest {}
+
+        let r = map.grid.values().filter(|&q| *q == Tile::Sand).count();
+        Ok(r.into())
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        let mut map = parse_map(input)?;
+        map.check_source(self.params.source)?;
+        let floor = map.floor + 2;
+
+        while map.pour(self.params.source, Some(floor)) == Fall::Rest {}
+
+        let r = map.grid.values().filter(|&q| *q == Tile::Sand).count();
+        Ok(r.into())
+    }
+
+    fn configure(&mut self, args: &[String]) -> Result<()> {
+        aoc_core::configure(&mut self.params, args)
+    }
+}
@@ -0,0 +1,30 @@
+use aoc_core::{Answer, Solver};
+use d14::Solution;
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part_1() {
+    assert_eq!(Solution::default().part_1(EXAMPLE).unwrap(), Answer::Number(24));
+}
+
+#[test]
+fn part_2() {
+    assert_eq!(Solution::default().part_2(EXAMPLE).unwrap(), Answer::Number(93));
+}
+
+#[test]
+fn bad_source() {
+    let mut solution = Solution::default();
+    solution.configure(&["--source=498,4".to_string()]).unwrap();
+    let e = solution.part_1(EXAMPLE).unwrap_err();
+    assert_eq!(e.to_string(), "the source 498,4 is on a rock");
+
+    solution.configure(&["--source=500,20".to_string()]).unwrap();
+    let e = solution.part_2(EXAMPLE).unwrap_err();
+    assert_eq!(e.to_string(), "the source 500,20 must be above the lowest rock, at y=9");
+
+    // Sand poured inside the cup fills it, then covers the source
+    solution.configure(&["--source=500,8".to_string()]).unwrap();
+    assert_eq!(solution.part_1(EXAMPLE).unwrap(), Answer::Number(1));
+}
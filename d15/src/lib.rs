@@ -0,0 +1,171 @@
+#![allow(unused_imports)]
+
+use std::ops::Range;
+
+use aoc_core::parse::{self, NomResult};
+use aoc_core::{Answer, Solver};
+use clap::Parser;
+use eyre::{eyre, Result};
+use fxhash::FxHashMap;
+use fxhash::FxHashSet;
+use nom::bytes::complete::tag;
+use nom::character::complete::{char, digit1};
+use nom::combinator::*;
+use nom::error::context;
+use nom::multi::*;
+use nom::sequence::{pair, separated_pair};
+
+// Input format:
+// Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+
+type Point = (i64, i64);
+
+fn decimal(s: &str) -> NomResult<'_, i64> {
+    context("a number", map_res(recognize(pair(opt(char('-')), digit1)), str::parse))(s)
+}
+
+fn point(s: &str) -> NomResult<'_, Point> {
+    separated_pair(decimal, context("`, y=`", tag(", y=")), decimal)(s)
+}
+
+fn reading(s: &str) -> NomResult<'_, (Point, Point)> {
+    let (s, _) = context("`Sensor at x=`", tag("Sensor at x="))(s)?;
+    let (s, p1) = point(s)?;
+    let (s, _) = context("`: closest beacon is at x=`", tag(": closest beacon is at x="))(s)?;
+    let (s, p2) = point(s)?;
+    Ok((s, (p1, p2)))
+}
+
+fn distance(a: Point, b: Point) -> i64 {
+    (a.0 - b.0).abs() + (a.1 - b.1).abs()
+}
+
+#[derive(Default)]
+struct Map {
+    sensors: FxHashMap<Point, i64>,
+    beacons: FxHashSet<Point>,
+    bound_x: Range<i64>,
+    bound_y: Range<i64>,
+}
+
+impl Map {
+    fn mark_sensor(&mut self, s: Point, b: Point) {
+        let d = distance(s, b);
+        self.sensors.insert(s, d);
+        self.beacons.insert(b);
+        self.bound_x.start = self.bound_x.start.min(s.0 - d);
+        self.bound_x.end = self.bound_x.end.max(s.0 + d + 1);
+        self.bound_y.start = self.bound_y.start.min(s.1 - d);
+        self.bound_y.end = self.bound_y.end.max(s.1 + d + 1);
+    }
+
+    fn check_beacon(&self, p: Point) -> bool {
+        self.beacons.contains(&p)
+    }
+
+    fn check_blocked(&self, p: Point) -> bool {
+        self.sensors.iter().any(|(s, d)| distance(*s, p) <= *d)
+    }
+}
+
+struct ManhattanCircleIter {
+    c: Point,
+    d: i64,
+    idx: i64,
+}
+
+impl Iterator for ManhattanCircleIter {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let i = self.idx % self.d;
+        let next = match self.idx / self.d {
+            0 => (self.c.0 - self.d + i, self.c.1 + i),
+            1 => (self.c.0 + i, self.c.1 + self.d - i),
+            2 => (self.c.0 + self.d - i, self.c.1 - i),
+            3 => (self.c.0 - i, self.c.1 - self.d + i),
+            4.. => return None,
+            _ => unreachable!(),
+        };
+        self.idx += 1;
+        Some(next)
+    }
+}
+
+fn manhattan_circle(c: Point, d: i64) -> impl Iterator<Item=Point> {
+    ManhattanCircleIter {
+        c,
+        d,
+        idx: 0,
+    }
+}
+
+fn parse_map(input: &str) -> Result<Map> {
+    let mut map = Map::default();
+    for r in parse::lines(input, |l| Ok(parse::nom_line(l, reading)?)) {
+        let (s, b) = r?;
+        map.mark_sensor(s, b);
+    }
+    Ok(map)
+}
+
+/// Puzzle parameters, the defaults solve the real input
+#[derive(Clone, Debug, Parser)]
+pub struct Params {
+    /// Row checked in part 1 (10 in the example)
+    #[arg(long, default_value_t = 2000000)]
+    pub row: i64,
+    /// Largest coordinate of the distress beacon in part 2 (20 in the example)
+    #[arg(long, default_value_t = 4000000)]
+    pub bound: i64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self::parse_from([""])
+    }
+}
+
+#[derive(Default)]
+pub struct Solution {
+    pub params: Params,
+}
+
+impl Solver for Solution {
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_map(input)?;
+
+        Ok(())
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        let map = parse_map(input)?;
+        let row = self.params.row;
+
+        let r = map.bound_x.clone().filter(|i| !map.check_beacon((*i, row)) && map.check_blocked((*i, row))).count();
+
+        Ok(r.into())
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        let map = parse_map(input)?;
+        let bound = 0..=self.params.bound;
+
+        let p = map.sensors.iter()
+            .flat_map(|(s, d)| manhattan_circle(*s, *d + 1))
+            .filter(|p| bound.contains(&p.0) && bound.contains(&p.1))
+            .find(|&p| !map.check_blocked(p))
+            .ok_or_else(|| eyre!("no spot left for the distress beacon"))?;
+
+        let (i, j) = p;
+        let r = i.checked_mul(4000000)
+            .and_then(|r| r.checked_add(j))
+            .ok_or_else(|| eyre!("tuning frequency overflows"))?;
+
+        Ok(r.into())
+    }
+
+    fn configure(&mut self, args: &[String]) -> Result<()> {
+        aoc_core::configure(&mut self.params, args)
+    }
+}
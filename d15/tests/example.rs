@@ -0,0 +1,28 @@
+use aoc_core::{Answer, Solver};
+use d15::{Params, Solution};
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+fn example() -> Solution {
+    Solution {
+        params: Params { row: 10, bound: 20 },
+    }
+}
+
+#[test]
+fn part_1() {
+    assert_eq!(example().part_1(EXAMPLE).unwrap(), Answer::Number(26));
+}
+
+#[test]
+fn part_2() {
+    assert_eq!(example().part_2(EXAMPLE).unwrap(), Answer::Number(56000011));
+}
+
+#[test]
+fn configure() {
+    let mut solution = Solution::default();
+    solution.configure(&["--row".into(), "10".into()]).unwrap();
+    assert_eq!((solution.params.row, solution.params.bound), (10, 4000000));
+    assert!(solution.configure(&["--column".into(), "3".into()]).is_err());
+}
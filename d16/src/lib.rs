@@ -0,0 +1,210 @@
+//! I'm not really proud of this solution :(
+
+#![allow(unused_imports)]
+
+use std::{collections::{HashMap, HashSet}, mem::swap, borrow::Cow};
+
+use aoc_core::parse::{self, NomResult};
+use aoc_core::{Answer, Solver};
+use clap::Parser;
+use eyre::{bail, ensure, Result};
+use itertools::Itertools;
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_till, take_while},
+    character::complete::{alpha1, digit1},
+    combinator::{map_res, recognize, opt},
+    error::context,
+    multi::{many1, separated_list1},
+};
+use petgraph::{prelude::*, dot::{Dot, Config}, algo::floyd_warshall};
+use petgraph::algo::dijkstra;
+
+// Valve KR has flow rate=17; tunnels lead to valves WA, JQ, JY, KI
+
+fn parse_valve(s: &str) -> NomResult<'_, (String, Node)> {
+    let (s, _) = context("`Valve `", tag("Valve "))(s)?;
+    let (s, name) = context("a valve name", alpha1)(s)?;
+    let (s, _) = context("` has flow rate=`", tag(" has flow rate="))(s)?;
+    let (s, k) = context("a flow rate", map_res(digit1, str::parse))(s)?;
+    let (s, _) = context(
+        "`; tunnels lead to valves `",
+        alt((tag("; tunnels lead to valves "), tag("; tunnel leads to valve "))),
+    )(s)?;
+    let (s, succ) = separated_list1(tag(", "), context("a valve name", alpha1))(s)?;
+
+    Ok((
+        s,
+        (
+            name.to_string(),
+            Node {
+                k,
+                succ: succ.into_iter().map(String::from).collect(),
+            },
+        ),
+    ))
+}
+
+struct Node {
+    k: usize,
+    succ: Vec<String>,
+}
+
+struct SearchGraph<'a> {
+    values: HashMap<&'a str, isize>,
+    metagraph: HashMap<(&'a str, &'a str), isize>,
+    /// Bit of each valve worth opening in the sets of opened valves
+    bits: HashMap<&'a str, u64>,
+}
+
+
+impl<'a> From<&'a HashMap<String, Node>> for SearchGraph<'a> {
+    fn from(value: &'a HashMap<String, Node>) -> Self {
+        let mut graph: GraphMap<&str, isize, Directed> = GraphMap::new();
+
+        for (a, v) in value.iter() {
+            for b in v.succ.iter() {
+                graph.add_edge(a.as_str(), b.as_str(), 1);
+            }
+        }
+        let st = floyd_warshall(&graph, |e| *e.2).unwrap();
+
+        let values: HashMap<_, _> = value.iter()
+            .map(|(k, v)| (k.as_str(), v.k as isize))
+            .filter(|(_, v)| *v > 0)
+            .collect();
+        let bits = values.keys().enumerate().map(|(i, &k)| (k, 1 << i)).collect();
+
+        Self { metagraph: st, values, bits }
+    }
+}
+
+impl<'a> SearchGraph<'a> {
+    fn compute_score(&self, activated: &HashMap<&'a str, isize>) -> isize {
+        activated.iter()
+            .map(|(n, t)| self.values[n] * t)
+            .sum()
+    }
+
+    fn search(&self, start: &str, time: isize, activated: &mut HashMap<&'a str, isize>) -> isize {
+        let mut max = 0;
+        for &name in self.values.keys() {
+            if activated.contains_key(name) {
+                continue;
+            }
+            let d = self.metagraph[&(start, name)] + 1;
+            if time <= d {
+                continue;
+            }
+            activated.insert(name, time - d);
+            max = max.max(self.search(name, time - d, activated));
+            activated.remove(name);
+        }
+
+        max.max(self.compute_score(activated))
+    }
+
+    /// Best score of a single agent for every set of valves it can open in time
+    fn search_sets(&self, start: &str, time: isize, activated: &mut HashMap<&'a str, isize>, best: &mut HashMap<u64, isize>) {
+        let set = activated.keys().map(|n| self.bits[n]).fold(0, |acc, b| acc | b);
+        let score = self.compute_score(activated);
+        let e = best.entry(set).or_insert(0);
+        *e = (*e).max(score);
+
+        for &name in self.values.keys() {
+            if activated.contains_key(name) {
+                continue;
+            }
+            let d = self.metagraph[&(start, name)] + 1;
+            if time <= d {
+                continue;
+            }
+            activated.insert(name, time - d);
+            self.search_sets(name, time - d, activated, best);
+            activated.remove(name);
+        }
+    }
+}
+
+fn parse_map(input: &str) -> Result<HashMap<String, Node>> {
+    let valves = parse::lines(input, |l| Ok(parse::nom_line(l, parse_valve)?))
+        .collect::<Result<Vec<_>>>()?;
+    let names: HashSet<_> = valves.iter().map(|(name, _)| name.as_str()).collect();
+
+    for (i, (name, node)) in valves.iter().enumerate() {
+        if let Some(dest) = node.succ.iter().find(|d| !names.contains(d.as_str())) {
+            bail!("line {}: valve {name} leads to valve {dest}, which is not defined", i + 1);
+        }
+    }
+    Ok(valves.into_iter().collect())
+}
+
+/// Puzzle parameters, the defaults solve the real input
+#[derive(Clone, Debug, Parser)]
+pub struct Params {
+    /// Valve where the search starts
+    #[arg(long, default_value = "AA")]
+    pub start: String,
+    /// Minutes available in part 1
+    #[arg(long, default_value_t = 30)]
+    pub time_1: isize,
+    /// Minutes available in part 2, after teaching the elephant
+    #[arg(long, default_value_t = 26)]
+    pub time_2: isize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self::parse_from([""])
+    }
+}
+
+#[derive(Default)]
+pub struct Solution {
+    pub params: Params,
+}
+
+impl Solution {
+    fn search_graph<'a>(&self, map: &'a HashMap<String, Node>) -> Result<SearchGraph<'a>> {
+        ensure!(map.contains_key(&self.params.start), "no valve {} to start from", self.params.start);
+        Ok(SearchGraph::from(map))
+    }
+}
+
+impl Solver for Solution {
+    fn parse(&self, input: &str) -> Result<()> {
+        parse_map(input)?;
+
+        Ok(())
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        let map = parse_map(input)?;
+        let sg = self.search_graph(&map)?;
+
+        let mut acts = HashMap::new();
+        Ok(sg.search(&self.params.start, self.params.time_1, &mut acts).into())
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        let map = parse_map(input)?;
+        let sg = self.search_graph(&map)?;
+
+        // Both agents open disjoint sets of valves
+        let mut best = HashMap::new();
+        sg.search_sets(&self.params.start, self.params.time_2, &mut HashMap::new(), &mut best);
+        let best = best.into_iter().collect::<Vec<_>>();
+        let r = best.iter()
+            .flat_map(|a| best.iter().map(move |b| (a, b)))
+            .filter(|((a, _), (b, _))| a & b == 0)
+            .map(|((_, x), (_, y))| x + y)
+            .max()
+            .unwrap_or(0);
+
+        Ok(r.into())
+    }
+
+    fn configure(&mut self, args: &[String]) -> Result<()> {
+        aoc_core::configure(&mut self.params, args)
+    }
+}
@@ -0,0 +1,21 @@
+use aoc_core::{Answer, Solver};
+use d16::Solution;
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part_1() {
+    assert_eq!(Solution::default().part_1(EXAMPLE).unwrap(), Answer::Number(1651));
+}
+
+#[test]
+fn part_2() {
+    assert_eq!(Solution::default().part_2(EXAMPLE).unwrap(), Answer::Number(1707));
+}
+
+#[test]
+fn undefined_valve() {
+    let input = EXAMPLE.lines().take(3).collect::<Vec<_>>().join("\n");
+    let e = Solution::default().parse(&input).unwrap_err();
+    assert_eq!(e.to_string(), "line 1: valve AA leads to valve DD, which is not defined");
+}
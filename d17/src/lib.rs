@@ -0,0 +1,263 @@
+#![allow(unused_imports)]
+
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+
+use aoc_core::parse::ParseError;
+use aoc_core::{Answer, Solver};
+use clap::Parser;
+use eyre::{bail, ensure, Result};
+use aoc_grid::Grid;
+use itertools::Itertools;
+
+type Coord = (isize, isize);
+
+
+const SHAPES: &[&[Coord]] = &[
+    &[(0,0), (0,1), (0,2), (0,3)],
+    &[(0,1), (1,0), (1,1), (1,2), (2,1)],
+    &[(0,0), (0,1), (0,2), (1,2), (2,2)],
+    &[(0,0), (1,0), (2,0), (3,0)],
+    &[(0,0), (0,1), (1,0), (1,1)],
+];
+
+#[derive(Clone, Copy)]
+enum Dir {
+    Left = -1,
+    Right = 1,
+}
+
+fn jets(input: &str) -> Result<impl Iterator<Item = Dir> + '_> {
+    let s = input.trim();
+    if let Some((at, c)) = s.char_indices().find(|&(_, c)| c != '<' && c != '>') {
+        bail!(ParseError::new(input, &s[at..at + c.len_utf8()], "`<` or `>`"));
+    }
+    ensure!(!s.is_empty(), "no jets in the input");
+
+    Ok(s.chars().map(|c| match c {
+        '<' => Dir::Left,
+        _ => Dir::Right,
+    }).cycle())
+}
+
+#[derive(Default)]
+struct Game {
+    map: HashSet<Coord>,
+    bounds: Range<isize>,
+    top: isize,
+    active_piece: Option<Tetris>,
+    /// Pieces landed and jets used so far
+    pieces: usize,
+    jets_used: usize,
+    animate: bool,
+}
+
+#[derive(Clone, Copy)]
+struct Tetris {
+    shape: &'static [Coord],
+    anchor: Coord, // bottom left
+}
+
+impl Game {
+    fn new(bounds: Range<isize>) -> Self { Self { bounds, top: 0, ..Default::default() } }
+
+    /// Drop pieces until `n` of them have landed in total
+    fn run(&mut self, jets: impl Iterator<Item = Dir>, n: usize) {
+        if self.pieces == n {
+            return;
+        }
+
+        for d in jets {
+            if self.active_piece.is_none() {
+                self.active_piece = Some(Tetris { shape: SHAPES[self.pieces % SHAPES.len()], anchor: (self.top + 4, 2) });
+            }
+            self.jets_used += 1;
+
+            if self.animate {
+                println!("{}[2J", 27 as char);
+                self.print();
+                std::thread::sleep(std::time::Duration::from_millis(10));
+            }
+
+            
+            // Shift
+            let piece = self.active_piece.unwrap();
+            let try_pos = Tetris {
+                shape: piece.shape,
+                anchor: (piece.anchor.0, piece.anchor.1 + d as isize),
+            };
+
+            if self.is_clear(&try_pos) {
+                self.active_piece = Some(try_pos);
+            }
+
+            // Drop
+            let piece = self.active_piece.unwrap();
+            let try_pos = Tetris {
+                shape: piece.shape,
+                anchor: (piece.anchor.0 - 1, piece.anchor.1),
+            };
+
+            if self.is_clear(&try_pos) {
+                self.active_piece = Some(try_pos);
+            } else {
+                self.set_tetris(&piece);
+                self.active_piece.take();
+                self.pieces += 1;
+                if self.pieces == n {
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Depth of the highest block of every column below the top, to spot a repeating state
+    fn skyline(&self) -> Vec<isize> {
+        self.bounds.clone()
+            .map(|j| (0..self.top).find(|d| self.map.contains(&(self.top - d, j))).unwrap_or(self.top))
+            .collect()
+    }
+
+    fn set_tetris(&mut self, t: &Tetris) {
+        t.shape.iter()
+            .map(|offs| (offs.0 + t.anchor.0, offs.1 + t.anchor.1))
+            .for_each(|c| { assert!(self.map.insert(c)); });
+
+        self.top = self.top.max(t.shape.iter()
+            .map(|offs| offs.0 + t.anchor.0)
+            .max()
+            .unwrap());
+    }
+
+    fn is_clear(&self, t: &Tetris) -> bool {
+        t.shape.iter()
+            .map(|offs| (offs.0 + t.anchor.0, offs.1 + t.anchor.1))
+            .all(|c| c.0 > 0 && self.bounds.contains(&c.1) && !self.map.contains(&c))
+    }
+
+    fn print(&self) {
+        let active = self.active_piece.map(|t| t.shape.iter()
+                .map(|offs| (offs.0 + t.anchor.0, offs.1 + t.anchor.1))
+                .collect::<HashSet<_>>())
+            .unwrap_or_default();
+
+        let bottom = 1.max(self.top - 25);
+        let top = self.top + 5;
+        let view = Grid::from_fn((top - bottom) as usize, self.bounds.len(), |(r, c)| {
+            let (i, j) = (top - 1 - r as isize, self.bounds.start + c as isize);
+            if self.map.contains(&(i, j)) {
+                '#'
+            } else if active.contains(&(i, j)) {
+                '@'
+            } else {
+                '.'
+            }
+        });
+        for line in view.to_string().lines() {
+            println!("|{line}|");
+        }
+        if bottom == 1 {
+            println!("+{}+", "-".repeat(self.bounds.len()));
+        }
+    }
+}
+
+// |..@....|
+// |.@@@...|
+// |..@....|
+// |.......|
+// |..####.|
+// +-------+
+
+
+/// Puzzle parameters, the defaults solve the real input
+#[derive(Clone, Debug, Parser)]
+pub struct Params {
+    /// Pieces dropped in part 1
+    #[arg(long, default_value_t = 2022)]
+    pub pieces_1: usize,
+    /// Pieces dropped in part 2
+    #[arg(long, default_value_t = 1_000_000_000_000)]
+    pub pieces_2: usize,
+    /// Width of the chamber
+    #[arg(long, default_value_t = 7)]
+    pub width: isize,
+    /// Show every step of the first part before its final board
+    #[arg(long)]
+    pub animate: bool,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self::parse_from([""])
+    }
+}
+
+#[derive(Default)]
+pub struct Solution {
+    pub params: Params,
+}
+
+impl Solution {
+    fn game(&self) -> Result<Game> {
+        ensure!(self.params.width >= 4, "the chamber must be at least 4 wide to fit the pieces");
+        Ok(Game::new(0..self.params.width))
+    }
+
+    /// Play the first part, showing every step of the simulation with `--animate`, then print
+    /// the final board
+    pub fn print_board(&self, input: &str) -> Result<()> {
+        let jets = jets(input)?;
+        let mut map = self.game()?;
+        map.animate = self.params.animate;
+
+        map.run(jets, self.params.pieces_1);
+
+        map.print();
+
+        Ok(())
+    }
+}
+
+impl Solver for Solution {
+    fn parse(&self, input: &str) -> Result<()> {
+        let _ = jets(input)?;
+
+        Ok(())
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        let jets = jets(input)?;
+        let mut map = self.game()?;
+
+        map.run(jets, self.params.pieces_1);
+
+        Ok(map.top.into())
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        let n = self.params.pieces_2;
+        let mut jets = jets(input)?;
+        let n_jets = input.trim().len();
+        let mut map = self.game()?;
+
+        // Once a state repeats, the tower grows by the same height every period
+        let mut seen = HashMap::new();
+        while map.pieces < n {
+            map.run(&mut jets, map.pieces + 1);
+            let state = (map.pieces % SHAPES.len(), map.jets_used % n_jets, map.skyline());
+            if let Some((pieces, top)) = seen.insert(state, (map.pieces, map.top)) {
+                let (period, growth) = (map.pieces - pieces, map.top - top);
+                let cycles = (n - map.pieces) / period;
+                map.run(&mut jets, n - cycles * period);
+                return Ok((map.top + cycles as isize * growth).into());
+            }
+        }
+
+        Ok(map.top.into())
+    }
+
+    fn configure(&mut self, args: &[String]) -> Result<()> {
+        aoc_core::configure(&mut self.params, args)
+    }
+}
@@ -1,24 +1,21 @@
 #![allow(unused_imports)]
 
+use aoc_core::{parse, Answer, Solver};
 use eyre::Result;
 use itertools::Itertools;
 
-fn main() -> Result<()> {
-    let input = std::io::read_to_string(std::io::stdin())?;
+pub struct Solution;
 
-    // PART 1
+impl Solver for Solution {
+    fn parse(&self, _input: &str) -> Result<()> {
+        Ok(())
+    }
 
+    fn part_1(&self, _input: &str) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
 
-    // println!("{r}");
-
-    // PART 2
-
-
-    // println!("{r}");
-    Ok(())
+    fn part_2(&self, _input: &str) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
 }
-
-eyre = "0.6.8"
-itertools = "0.10.5"
-ndarray = "0.15.6"
-nom = "7.1.1"